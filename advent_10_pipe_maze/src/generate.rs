@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use aoc_common::{random, Grid, Rng};
+
+const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+// A `size`x`size` field of junk pipes crossed by one loop that never touches itself.
+// Only the two loop pipes next to `S` lead into it
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let cells = size.max(5).saturating_sub(1) / 2;
+
+    let corners = random::simple_loop(&mut rng, cells);
+
+    // Corner (r, c) of the blob is tile (2r, 2c), the tiles in between are straight pipes
+    let mut tiles = Grid::filled(2 * cells + 1, 2 * cells + 1, '.');
+    let mut path = Vec::new();
+    for (i, &(row, col)) in corners.iter().enumerate() {
+        let (next_row, next_col) = corners[(i + 1) % corners.len()];
+        path.push((2 * row, 2 * col));
+        path.push((row + next_row, col + next_col));
+    }
+
+    for (i, &pos) in path.iter().enumerate() {
+        let before = path[(i + path.len() - 1) % path.len()];
+        let after = path[(i + 1) % path.len()];
+        tiles[pos] = pipe(pos, before, after);
+    }
+
+    let on_loop = path.iter().copied().collect::<HashSet<_>>();
+    for pos in tiles.positions().collect::<Vec<_>>() {
+        if !on_loop.contains(&pos) {
+            tiles[pos] = *rng.choose(&JUNK);
+        }
+    }
+
+    let start = *rng.choose(&path);
+    tiles[start] = 'S';
+    for pos in tiles.neighbours4(start).collect::<Vec<_>>() {
+        if !on_loop.contains(&pos) {
+            tiles[pos] = '.';
+        }
+    }
+
+    tiles.to_string() + "\n"
+}
+
+fn pipe(pos: (usize, usize), before: (usize, usize), after: (usize, usize)) -> char {
+    let side = |other: (usize, usize)| match (other.0 as isize - pos.0 as isize, other.1 as isize - pos.1 as isize) {
+        (-1, 0) => 'N',
+        (1, 0) => 'S',
+        (0, -1) => 'W',
+        _ => 'E',
+    };
+
+    let mut sides = [side(before), side(after)];
+    sides.sort();
+    match sides {
+        ['N', 'S'] => '|',
+        ['E', 'W'] => '-',
+        ['E', 'N'] => 'L',
+        ['N', 'W'] => 'J',
+        ['S', 'W'] => '7',
+        _ => 'F',
+    }
+}
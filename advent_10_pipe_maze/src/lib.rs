@@ -0,0 +1,280 @@
+pub mod generate;
+pub mod visualise;
+
+use std::fmt;
+
+use aoc_common::{search::{bfs, Graph, Reached}, trace, Dir4, Grid, Pos, Print, Result, Solution};
+
+#[derive(PartialEq)]
+pub struct Map {
+    pub start: Pos,
+    pub pipes: Grid<Option<Pipe>>,
+}
+
+impl Map {
+    // Every pipe connected to `S`, with how many steps along the loop it is away from it
+    fn find_loop(&self) -> Reached<Pos> {
+        bfs(self, self.start)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut labels = self.pipes.map(|pipe| pipe.as_ref().map_or('.', Pipe::label));
+        labels[self.start] = 'S';
+        write!(f, "{labels}")
+    }
+}
+
+// Steps between pipes that connect to each other
+impl Graph for Map {
+    type Node = Pos;
+
+    fn neighbours(&self, &from: &Pos) -> Vec<(Pos, usize)> {
+        let Some(connector) = &self.pipes[from] else {
+            return Vec::new();
+        };
+
+        Dir4::ALL.into_iter()
+            .filter(|&dir| connector.connects(dir))
+            .filter_map(|dir| self.pipes.step(from, dir).map(|to| (to, dir)))
+            .filter(|&(to, dir)| self.pipes[to].as_ref().is_some_and(|connectee| connectee.connects(dir.opposite())))
+            .map(|(to, _)| (to, 1))
+            .collect()
+    }
+}
+
+// The loop is needed by both parts, so it is traced once while parsing
+pub struct Maze {
+    pub map: Map,
+    pub pipe_loop: Reached<Pos>,
+}
+
+// The loop follows from the map
+impl PartialEq for Maze {
+    fn eq(&self, other: &Maze) -> bool {
+        self.map == other.map
+    }
+}
+
+impl Maze {
+    // Tiles enclosed by the loop, found by counting loop crossings along each row. A run along the
+    // loop crosses it when it leaves on the other side than it came in on, like `L--7`
+    fn inside(&self) -> Grid<bool> {
+        let mut tiles = Grid::filled(self.map.pipes.rows(), self.map.pipes.cols(), false);
+        for x in 0..self.map.pipes.rows() {
+            let mut inside = false;
+            let mut came_from = None;
+            for y in 0..self.map.pipes.cols() {
+                if self.pipe_loop.contains(&(x, y)) {
+                    let pipe = self.map.pipes[(x, y)].as_ref().expect("the loop is made of pipes");
+
+                    match (&pipe.pipe_type, pipe.ends) {
+                        (PipeType::Vertical, _) => inside = !inside,
+                        (PipeType::Bend, &[side, Dir4::East]) => came_from = Some(side),
+                        (PipeType::Bend, &[side, Dir4::West]) if came_from == Some(side.opposite()) => inside = !inside,
+                        _ => (),
+                    }
+                }
+                else if inside {
+                    tiles[(x, y)] = true;
+                }
+            }
+            trace!(trace::STEP, "row", "row": x, "inside": tiles.row(x).iter().filter(|inside| **inside).count());
+        }
+
+        tiles
+    }
+}
+
+#[derive(PartialEq)]
+pub struct Pipe {
+    pub pipe_type: PipeType,
+    // The sides it connects
+    pub ends: &'static [Dir4],
+}
+
+impl Pipe {
+    fn new(c: char) -> Option<Pipe> {
+        use Dir4::*;
+
+        let pipe = match c {
+            '|' => Pipe { pipe_type: PipeType::Vertical, ends: &[North, South] },
+            '-' => Pipe { pipe_type: PipeType::Horizontal, ends: &[East, West] },
+            'L' => Pipe { pipe_type: PipeType::Bend, ends: &[North, East] },
+            'J' => Pipe { pipe_type: PipeType::Bend, ends: &[North, West] },
+            '7' => Pipe { pipe_type: PipeType::Bend, ends: &[South, West] },
+            'F' => Pipe { pipe_type: PipeType::Bend, ends: &[South, East] },
+            _ => return None
+        };
+
+        Some(pipe)
+    }
+
+    // The pipe connecting exactly the given sides
+    fn joining(sides: &[Dir4]) -> Option<Pipe> {
+        "|-LJ7F".chars().filter_map(Pipe::new).find(|pipe| sides.len() == 2 && sides.iter().all(|&side| pipe.connects(side)))
+    }
+
+    fn connects(&self, side: Dir4) -> bool {
+        self.ends.contains(&side)
+    }
+
+    pub(crate) fn label(&self) -> char {
+        use Dir4::*;
+
+        match self.ends {
+            [North, South] => '|',
+            [East, West] => '-',
+            [North, East] => 'L',
+            [North, West] => 'J',
+            [South, West] => '7',
+            [South, East] => 'F',
+            _ => 'S',
+        }
+    }
+}
+
+#[derive(PartialEq)]
+pub enum PipeType {
+    Vertical,
+    Horizontal,
+    Bend
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Maze> {
+        let tiles = Grid::parse(input, "a pipe, `.` or `S`", |c| matches!(c, '|' | '-' | 'L' | 'J' | '7' | 'F' | 'S' | '.').then_some(c))?;
+        let start = tiles.find(|c| *c == 'S').ok_or("No starting position `S` in the maze")?;
+
+        let mut pipes = Grid::new(tiles.rows(), tiles.cols(), tiles.iter().map(|(_, c)| Pipe::new(*c)).collect());
+
+        // The sketch hides the pipe under `S`, it is the one joining the two pipes that connect to it
+        let sides = Dir4::ALL.into_iter()
+            .filter(|&dir| pipes.step(start, dir).and_then(|to| pipes[to].as_ref()).is_some_and(|pipe| pipe.connects(dir.opposite())))
+            .collect::<Vec<_>>();
+        pipes[start] = Some(Pipe::joining(&sides).ok_or_else(|| format!("{} pipes connect to `S`, the loop needs two", sides.len()))?);
+
+        let map = Map { start, pipes };
+        let pipe_loop = map.find_loop();
+
+        Ok(Maze { map, pipe_loop })
+    }
+
+    fn part1(maze: &Maze) -> Result<usize> {
+        let (at, farthest_point) = maze.pipe_loop.iter().max_by_key(|(_, steps)| *steps).map_or((maze.map.start, 0), |(&at, steps)| (at, steps));
+        trace!(trace::MILESTONE, "farthest", "at": [at.0, at.1], "steps": farthest_point, "loop": maze.pipe_loop.len());
+        Ok(farthest_point)
+    }
+
+    fn part2(maze: &Maze) -> Result<i32> {
+        let count = maze.inside().iter().filter(|(_, inside)| **inside).count();
+        Ok(count as i32)
+    }
+}
+
+impl Print for Day10 {
+    fn print(maze: &Maze) -> String {
+        maze.map.to_string()
+    }
+}
+
+/* --- Day 10: Pipe Maze ---
+You use the hang glider to ride the hot air from Desert Island all the way up to the floating metal island. This island is surprisingly cold and there definitely aren't any thermals to glide on, so you leave your hang glider behind.
+
+You wander around for a while, but you don't find any people or animals. However, you do occasionally find signposts labeled "Hot Springs" pointing in a seemingly consistent direction; maybe you can find someone at the hot springs and ask them where the desert-machine parts are made.
+
+The landscape here is alien; even the flowers and trees are made of metal. As you stop to admire some metal grass, you notice something metallic scurry away in your peripheral vision and jump into a big pipe! It didn't look like any animal you've ever seen; if you want a better look, you'll need to get ahead of it.
+
+Scanning the area, you discover that the entire field you're standing on is densely packed with pipes; it was hard to tell at first because they're the same metallic silver color as the "ground". You make a quick sketch of all of the surface pipes you can see (your puzzle input).
+
+The pipes are arranged in a two-dimensional grid of tiles:
+
+| is a vertical pipe connecting north and south.
+- is a horizontal pipe connecting east and west.
+L is a 90-degree bend connecting north and east.
+J is a 90-degree bend connecting north and west.
+7 is a 90-degree bend connecting south and west.
+F is a 90-degree bend connecting south and east.
+. is ground; there is no pipe in this tile.
+S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+Based on the acoustics of the animal's scurrying, you're confident the pipe that contains the animal is one large, continuous loop.
+
+For example, here is a square loop of pipe:
+
+.....
+.F-7.
+.|.|.
+.L-J.
+.....
+If the animal had entered this loop in the northwest corner, the sketch would instead look like this:
+
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+In the above diagram, the S tile is still a 90-degree F bend: you can tell because of how the adjacent pipes connect to it.
+
+Unfortunately, there are also many pipes that aren't connected to the loop! This sketch shows the same loop as above:
+
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+In the above diagram, you can still figure out which pipes form the main loop: they're the ones connected to S, pipes those pipes connect to, pipes those pipes connect to, and so on. Every pipe in the main loop connects to its two neighbors (including S, which will have exactly two pipes connecting to it, and which is assumed to connect back to those two pipes).
+
+Here is a sketch that contains a slightly more complex main loop:
+
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+Here's the same example sketch with the extra, non-main-loop pipe tiles also shown:
+
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+If you want to get out ahead of the animal, you should find the tile in the loop that is farthest from the starting position. Because the animal is in the pipe, it doesn't make sense to measure this by direct distance. Instead, you need to find the tile that would take the longest number of steps along the loop to reach from the starting point - regardless of which way around the loop the animal went.
+
+In the first example with the square loop:
+
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+You can count the distance each tile in the loop is from the starting point like this:
+
+.....
+.012.
+.1.3.
+.234.
+.....
+In this example, the farthest point from the start is 4 steps away.
+
+Here's the more complex loop again:
+
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+Here are the distances for each tile on that loop:
+
+..45.
+.236.
+01.78
+14567
+23...
+Find the single giant loop starting at S. How many steps along the loop does it take to get from the starting position to the point farthest from the starting position?*/
\ No newline at end of file
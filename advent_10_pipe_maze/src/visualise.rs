@@ -0,0 +1,18 @@
+use aoc_common::render::{Color, Frame, Style, Visualise};
+
+use crate::{Day10, Maze};
+
+// The loop in yellow from `S`, the tiles it encloses as green `I`s and the junk pipes greyed out
+impl Visualise for Day10 {
+    fn frames(maze: &Maze) -> Vec<Frame> {
+        let inside = maze.inside();
+
+        vec![Frame::new("The loop and the tiles it encloses", &maze.map.pipes, |pos, pipe| match pipe {
+            Some(_) if pos == maze.map.start => Style::new('S', Color::RED),
+            Some(pipe) if maze.pipe_loop.contains(&pos) => Style::new(pipe.label(), Color::YELLOW),
+            _ if inside[pos] => Style::new('I', Color::GREEN),
+            Some(pipe) => Style::new(pipe.label(), Color::GREY),
+            None => Style::new('.', Color::GREY),
+        })]
+    }
+}
@@ -0,0 +1,33 @@
+use advent_10_pipe_maze::Day10;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE_1: &str = include_str!("../fixtures/example_1.txt");
+const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+const EXAMPLE_3: &str = include_str!("../fixtures/example_3.txt");
+const EXAMPLE_4: &str = include_str!("../fixtures/example_4.txt");
+
+#[test]
+fn part1_square_loop() {
+    assert_eq!(part1::<Day10>(EXAMPLE_1), "4");
+}
+
+#[test]
+fn part1_square_loop_with_noise() {
+    assert_eq!(part1::<Day10>(EXAMPLE_2), "4");
+}
+
+#[test]
+fn part1_complex_loop() {
+    assert_eq!(part1::<Day10>(EXAMPLE_3), "8");
+}
+
+#[test]
+fn part1_complex_loop_with_noise() {
+    assert_eq!(part1::<Day10>(EXAMPLE_4), "8");
+}
+
+#[test]
+fn start_is_the_pipe_it_joins() {
+    assert_eq!(part2::<Day10>(".F-7.\n.S.|.\n.L-J.\n"), "1");
+    assert_eq!(part2::<Day10>(".F-7.\n.|.S.\n.L-J.\n"), "1");
+}
@@ -0,0 +1,17 @@
+use aoc_common::{Grid, Rng};
+
+// A `size`x`size` image with a few galaxies, about one row and column in ten stays empty
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let empty_rows = (0..size).map(|_| rng.chance(0.1)).collect::<Vec<_>>();
+    let empty_cols = (0..size).map(|_| rng.chance(0.1)).collect::<Vec<_>>();
+
+    let mut image = Grid::filled(size, size, '.');
+    for (row, col) in image.positions().collect::<Vec<_>>() {
+        if !empty_rows[row] && !empty_cols[col] && rng.chance(0.05) {
+            image[(row, col)] = '#';
+        }
+    }
+
+    image.to_string() + "\n"
+}
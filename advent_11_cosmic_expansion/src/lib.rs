@@ -0,0 +1,220 @@
+pub mod generate;
+
+use std::fmt;
+
+use itertools::Itertools;
+use aoc_common::{params, trace, Configurable, Grid, Print, Result, Solution};
+
+#[derive(PartialEq)]
+pub struct Universe {
+    pub galaxies: Vec<(i64, i64)>,
+    pub empty_rows: Vec<i64>,
+    pub empty_cols: Vec<i64>,
+}
+
+impl Universe {
+    // Every empty row and column before a galaxy pushes it further away by the modifier
+    fn expand(&self, modifier: i64) -> Vec<(i64, i64)> {
+        self.galaxies.iter().map(|&(x, y)| {
+            let expander_row = self.empty_rows.iter().filter(|&&row| row < x).count() as i64 * modifier;
+            let expander_col = self.empty_cols.iter().filter(|&&col| col < y).count() as i64 * modifier;
+
+            trace!(trace::STEP, "galaxy", "from": [x, y], "to": [x + expander_row, y + expander_col]);
+            (x + expander_row, y + expander_col)
+        }).collect()
+    }
+}
+
+// Every row and column either holds a galaxy or is empty, so the last of them gives the size of the image
+impl fmt::Display for Universe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = |galaxies: Vec<i64>, empty: &[i64]| galaxies.into_iter().chain(empty.iter().copied()).max().map_or(0, |last| last as usize + 1);
+        let rows = size(self.galaxies.iter().map(|&(x, _)| x).collect(), &self.empty_rows);
+        let cols = size(self.galaxies.iter().map(|&(_, y)| y).collect(), &self.empty_cols);
+
+        let mut image = Grid::filled(rows, cols, '.');
+        for &(x, y) in &self.galaxies {
+            image[(x as usize, y as usize)] = '#';
+        }
+
+        write!(f, "{image}")
+    }
+}
+
+params! {
+    // How many rows or columns every empty one stands for in each part
+    pub struct Day11Params {
+        expansion_pt1: i64 = 2,
+        expansion_pt2: i64 = 1_000_000,
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Universe;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Universe> {
+        let image = Grid::parse(input, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        })?;
+
+        let galaxies = image.iter().filter(|(_, galaxy)| **galaxy).map(|((x, y), _)| (x as i64, y as i64)).collect();
+        let empty_rows = (0..image.rows()).filter(|&x| !image.row(x).contains(&true)).map(|x| x as i64).collect();
+        let empty_cols = (0..image.cols()).filter(|&y| !image.column(y).any(|galaxy| *galaxy)).map(|y| y as i64).collect();
+
+        Ok(Universe { galaxies, empty_rows, empty_cols })
+    }
+
+    fn part1(universe: &Universe) -> Result<i64> {
+        Self::part1_with(universe, &Day11Params::default())
+    }
+
+    fn part2(universe: &Universe) -> Result<i64> {
+        Self::part2_with(universe, &Day11Params::default())
+    }
+}
+
+impl Configurable for Day11 {
+    type Params = Day11Params;
+
+    fn part1_with(universe: &Universe, params: &Day11Params) -> Result<i64> {
+        let tot_dist = total_distance(universe, modifier(params.expansion_pt1)?);
+        Ok(tot_dist)
+    }
+
+    fn part2_with(universe: &Universe, params: &Day11Params) -> Result<i64> {
+        let tot_dist = total_distance(universe, modifier(params.expansion_pt2)?);
+        Ok(tot_dist)
+    }
+}
+
+// An empty line that stands for `expansion` lines adds one less than that
+fn modifier(expansion: i64) -> Result<i64> {
+    match expansion {
+        1.. => Ok(expansion - 1),
+        _ => Err(format!("An empty line can't stand for {expansion} lines, it needs at least 1").into()),
+    }
+}
+
+pub fn total_distance(universe: &Universe, modifier: i64) -> i64 {
+    trace!(trace::MILESTONE, "expand", "galaxies": universe.galaxies.len(), "empty_rows": universe.empty_rows.len(), "empty_cols": universe.empty_cols.len(), "by": modifier);
+    universe.expand(modifier).iter()
+        .tuple_combinations()
+        .unique()
+        .map(get_distance)
+        .sum::<i64>()
+}
+
+fn get_distance((from, to): (&(i64, i64), &(i64, i64))) -> i64 {
+    (from.0 - to.0).abs() + (from.1 - to.1).abs() 
+}
+
+impl Print for Day11 {
+    fn print(universe: &Universe) -> String {
+        universe.to_string()
+    }
+}
+
+/* --- Day 11: Cosmic Expansion ---
+You continue following signs for "Hot Springs" and eventually come across an observatory. The Elf within turns out to be a researcher studying cosmic expansion using the giant telescope here.
+
+He doesn't know anything about the missing machine parts; he's only visiting for this research project. However, he confirms that the hot springs are the next-closest area likely to have people; he'll even take you straight there once he's done with today's observation analysis.
+
+Maybe you can help him with the analysis to speed things up?
+
+The researcher has collected a bunch of data and compiled the data into a single giant image (your puzzle input). The image includes empty space (.) and galaxies (#). For example:
+
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+The researcher is trying to figure out the sum of the lengths of the shortest path between every pair of galaxies. However, there's a catch: the universe expanded in the time it took the light from those galaxies to reach the observatory.
+
+Due to something involving gravitational effects, only some space expands. In fact, the result is that any rows or columns that contain no galaxies should all actually be twice as big.
+
+In the above example, three columns and two rows contain no galaxies:
+
+   v  v  v
+ ...#......
+ .......#..
+ #.........
+>..........<
+ ......#...
+ .#........
+ .........#
+>..........<
+ .......#..
+ #...#.....
+   ^  ^  ^
+These rows and columns need to be twice as big; the result of cosmic expansion therefore looks like this:
+
+....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......
+Equipped with this expanded universe, the shortest path between every pair of galaxies can be found. It can help to assign every galaxy a unique number:
+
+....1........
+.........2...
+3............
+.............
+.............
+........4....
+.5...........
+............6
+.............
+.............
+.........7...
+8....9.......
+In these 9 galaxies, there are 36 pairs. Only count each pair once; order within the pair doesn't matter. For each pair, find any shortest path between the two galaxies using only steps that move up, down, left, or right exactly one . or # at a time. (The shortest path between two galaxies is allowed to pass through another galaxy.)
+
+For example, here is one of the shortest paths between galaxies 5 and 9:
+
+....1........
+.........2...
+3............
+.............
+.............
+........4....
+.5...........
+.##.........6
+..##.........
+...##........
+....##...7...
+8....9.......
+This path has length 9 because it takes a minimum of nine steps to get from galaxy 5 to galaxy 9 (the eight locations marked # plus the step onto galaxy 9 itself). Here are some other example shortest path lengths:
+
+Between galaxy 1 and galaxy 7: 15
+Between galaxy 3 and galaxy 6: 17
+Between galaxy 8 and galaxy 9: 5
+In this example, after expanding the universe, the sum of the shortest path between all 36 pairs of galaxies is 374.
+
+Expand the universe, then find the length of the shortest path between every pair of galaxies. What is the sum of these lengths? 
+
+--- Part Two ---
+The galaxies are much older (and thus much farther apart) than the researcher initially estimated.
+
+Now, instead of the expansion you did before, make each empty row or column one million times larger. That is, each empty row should be replaced with 1000000 empty rows, and each empty column should be replaced with 1000000 empty columns.
+
+(In the example above, if each empty row or column were merely 10 times larger, the sum of the shortest paths between every pair of galaxies would be 1030. If each empty row or column were merely 100 times larger, the sum of the shortest paths between every pair of galaxies would be 8410. However, your universe will need to expand far beyond these values.)
+
+Starting with the same initial image, expand the universe according to these new rules, then find the length of the shortest path between every pair of galaxies. What is the sum of these lengths?*/
\ No newline at end of file
@@ -0,0 +1,20 @@
+use advent_11_cosmic_expansion::{total_distance, Day11, Day11Params};
+use aoc_common::testing::{parse, part1, part2_with};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day11>(EXAMPLE), "374");
+}
+
+// The puzzle only documents 10 and 100 times larger, each empty line adds the modifier on top of itself
+#[test]
+fn part2_example() {
+    let universe = parse::<Day11>(EXAMPLE);
+
+    assert_eq!(total_distance(&universe, 9), 1030);
+    assert_eq!(total_distance(&universe, 99), 8410);
+    assert_eq!(part2_with::<Day11>(EXAMPLE, &Day11Params { expansion_pt2: 10, ..Day11Params::default() }), "1030");
+    assert_eq!(part2_with::<Day11>(EXAMPLE, &Day11Params { expansion_pt2: 100, ..Day11Params::default() }), "8410");
+}
@@ -0,0 +1,45 @@
+use aoc_common::Rng;
+
+// `size` condition records of up to 20 springs
+pub fn generate(seed: u64, size: usize) -> String {
+    unfolded(seed, size, 1)
+}
+
+// The same records as `generate`, already unfolded `unfold` times, so part 1 of
+// `unfolded(seed, size, 5)` matches part 2 of `generate(seed, size)`
+pub fn unfolded(seed: u64, size: usize, unfold: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size).map(|_| {
+        let (pattern, groups) = record(&mut rng);
+        let pattern = vec![pattern; unfold].join("?");
+        let groups = vec![groups; unfold].join(",");
+
+        format!("{pattern} {groups}\n")
+    }).collect()
+}
+
+// Lays out the damaged groups of a row and then hides some of the springs behind `?`
+fn record(rng: &mut Rng) -> (String, String) {
+    let len = rng.range(4..=20) as usize;
+
+    let mut springs = vec!['.'; len];
+    let mut groups = Vec::new();
+    let mut col = rng.below(3);
+    while col < len {
+        let group = (rng.range(1..=5) as usize).min(len - col);
+        springs[col..col + group].fill('#');
+        groups.push(group.to_string());
+        col += group + rng.range(1..=4) as usize;
+    }
+
+    // A row needs at least one damaged group
+    if groups.is_empty() {
+        springs[0] = '#';
+        groups.push("1".to_string());
+    }
+
+    let hidden = rng.range(20..=80) as f64 / 100.;
+    let pattern = springs.into_iter().map(|c| if rng.chance(hidden) { '?' } else { c }).collect();
+    (pattern, groups.join(","))
+}
@@ -0,0 +1,21 @@
+use advent_12_hot_springs::Day12;
+use aoc_common::{testing::{part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day12>(EXAMPLE), "21");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day12>(EXAMPLE), "525152");
+}
+
+#[test]
+fn empty_groups_are_an_error() {
+    let error = Day12::parse("???.### 1,0,3").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 1, column 11: expected a group of at least one spring, found `0`");
+}
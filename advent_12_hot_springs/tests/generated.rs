@@ -0,0 +1,9 @@
+use advent_12_hot_springs::{generate::{generate, unfolded}, Day12};
+use aoc_common::testing::{part1, part2};
+
+#[test]
+fn part2_counts_unfolded_records() {
+    for seed in 0..5 {
+        assert_eq!(part1::<Day12>(&unfolded(seed, 10, 5)), part2::<Day12>(&generate(seed, 10)));
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_common::{Grid, Rng};
+
+// A line of reflection between rows or columns, `at` being the number of rows or columns before it
+#[derive(Clone, Copy, PartialEq)]
+struct Line {
+    horizontal: bool,
+    at: usize,
+}
+
+// `size` patterns of 5 to 17 rows and columns. Each reflects across exactly one line and has exactly
+// one smudge, fixing it gives a different single line of reflection
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let patterns = (0..size).map(|_| loop {
+        if let Some(pattern) = pattern(&mut rng) {
+            break pattern.to_string();
+        }
+    }).collect::<Vec<_>>();
+
+    patterns.join("\n\n") + "\n"
+}
+
+// Cells mirrored by the clean line are equal, so are the ones mirrored by the smudged line except for
+// the smudge itself. None when the constraints force more than that, the caller simply retries
+fn pattern(rng: &mut Rng) -> Option<Grid<char>> {
+    let (rows, cols) = (rng.range(5..=17) as usize, rng.range(5..=17) as usize);
+    let line = |rng: &mut Rng| {
+        let horizontal = rng.chance(0.5);
+        Line { horizontal, at: rng.range(1..if horizontal { rows } else { cols } as u64) as usize }
+    };
+    let (clean, smudged) = (line(rng), line(rng));
+    if clean == smudged {
+        return None;
+    }
+
+    let mut classes = Classes::new(rows * cols);
+    for (a, b) in mirrored(clean, rows, cols) {
+        classes.union(a.0 * cols + a.1, b.0 * cols + b.1);
+    }
+    let pairs = mirrored(smudged, rows, cols);
+    let smudge = *rng.choose(&pairs);
+    for &(a, b) in pairs.iter().filter(|&&pair| pair != smudge) {
+        classes.union(a.0 * cols + a.1, b.0 * cols + b.1);
+    }
+
+    let (a, b) = (classes.find(smudge.0.0 * cols + smudge.0.1), classes.find(smudge.1.0 * cols + smudge.1.1));
+    if a == b {
+        return None;
+    }
+
+    let mut values = (0..rows * cols).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect::<Vec<_>>();
+    values[b] = if values[a] == '#' { '.' } else { '#' };
+    let pattern = Grid::new(rows, cols, (0..rows * cols).map(|cell| values[classes.find(cell)]).collect());
+
+    let lines = all_lines(rows, cols);
+    let exact = lines.iter().filter(|&&line| mismatches(&pattern, line) == 0).count();
+    let off_by_one = lines.iter().filter(|&&line| mismatches(&pattern, line) == 1).count();
+    (exact == 1 && off_by_one == 1).then_some(pattern)
+}
+
+fn all_lines(rows: usize, cols: usize) -> Vec<Line> {
+    (1..rows).map(|at| Line { horizontal: true, at }).chain((1..cols).map(|at| Line { horizontal: false, at })).collect()
+}
+
+// Pairs of cells that the line maps onto each other
+fn mirrored(line: Line, rows: usize, cols: usize) -> Vec<((usize, usize), (usize, usize))> {
+    let len = if line.horizontal { rows } else { cols };
+    let reach = line.at.min(len - line.at);
+
+    let mut pairs = Vec::new();
+    for offset in 0..reach {
+        let (near, far) = (line.at - 1 - offset, line.at + offset);
+        for other in 0..if line.horizontal { cols } else { rows } {
+            pairs.push(if line.horizontal { ((near, other), (far, other)) } else { ((other, near), (other, far)) });
+        }
+    }
+
+    pairs
+}
+
+fn mismatches(pattern: &Grid<char>, line: Line) -> usize {
+    mirrored(line, pattern.rows(), pattern.cols()).into_iter().filter(|&(a, b)| pattern[a] != pattern[b]).count()
+}
+
+// Union find over the cells of a pattern
+struct Classes {
+    parents: Vec<usize>,
+}
+
+impl Classes {
+    fn new(len: usize) -> Classes {
+        Classes { parents: (0..len).collect() }
+    }
+
+    fn find(&mut self, cell: usize) -> usize {
+        let parent = self.parents[cell];
+        if parent == cell {
+            return cell;
+        }
+
+        let root = self.find(parent);
+        self.parents[cell] = root;
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[a] = b;
+    }
+}
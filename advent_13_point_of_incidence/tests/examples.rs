@@ -0,0 +1,21 @@
+use advent_13_point_of_incidence::Day13;
+use aoc_common::{testing::{parse, part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day13>(EXAMPLE), "405");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day13>(EXAMPLE), "400");
+}
+
+#[test]
+fn patterns_without_a_reflection_are_an_error() {
+    let error = Day13::part1(&parse::<Day13>("#.\n..\n\n\n\n#.#\n##.\n")).unwrap_err();
+
+    assert_eq!(error.to_string(), "Pattern 1 has no line of reflection");
+}
@@ -0,0 +1,14 @@
+use aoc_common::{Grid, Rng};
+
+// A `size`x`size` platform, about a fifth of it round rocks and a tenth cube rocks
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let platform = Grid::new(size, size, (0..size * size).map(|_| match rng.below(10) {
+        0 | 1 => 'O',
+        2 => '#',
+        _ => '.',
+    }).collect());
+
+    platform.to_string() + "\n"
+}
@@ -0,0 +1,265 @@
+pub mod generate;
+pub mod visualise;
+
+use std::{collections::HashMap, fmt};
+use aoc_common::{params, trace, Configurable, Dir4, Grid, Pos, Print, Result, Solution};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    pub rocks: Grid<Option<Rock>>,
+}
+
+impl Platform {
+    // Rolls every round rock towards `dir` until it hits the edge or another rock. Each line is walked
+    // away from the edge the rocks roll to, keeping the first free spot they can roll into
+    fn tilt(&mut self, dir: Dir4) {
+        let (rows, cols) = (self.rocks.rows(), self.rocks.cols());
+        let edge: Vec<Pos> = match dir {
+            Dir4::North => (0..cols).map(|col| (0, col)).collect(),
+            Dir4::South => (0..cols).map(|col| (rows - 1, col)).collect(),
+            Dir4::West => (0..rows).map(|row| (row, 0)).collect(),
+            Dir4::East => (0..rows).map(|row| (row, cols - 1)).collect(),
+        };
+
+        for start in edge {
+            let mut free = Some(start);
+            let mut pos = Some(start);
+            while let Some(curr) = pos {
+                match self.rocks[curr] {
+                    Some(Rock::Moving) => {
+                        let spot = free.expect("the free spot is never past the rock");
+                        self.rocks[curr] = None;
+                        self.rocks[spot] = Some(Rock::Moving);
+                        free = self.rocks.step(spot, dir.opposite());
+                    },
+                    Some(Rock::Standing) => free = self.rocks.step(curr, dir.opposite()),
+                    None => (),
+                }
+                pos = self.rocks.step(curr, dir.opposite());
+            }
+        }
+    }
+
+    fn spin_cycle(&mut self) {
+        for dir in SPIN {
+            self.tilt(dir);
+        }
+    }
+
+    fn load(&self) -> usize {
+        self.rocks.iter()
+            .filter(|(_, rock)| **rock == Some(Rock::Moving))
+            .map(|((row, _), _)| self.rocks.rows() - row)
+            .sum()
+    }
+}
+
+// The tilts of a spin cycle in order
+const SPIN: [Dir4; 4] = [Dir4::North, Dir4::West, Dir4::South, Dir4::East];
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Rock {
+    Moving,
+    Standing
+}
+
+impl Rock {
+    fn to_rock(c: char) -> Option<Rock> {
+        match c {
+            'O' => Some(Rock::Moving),
+            '#' => Some(Rock::Standing),
+            _ => None
+        }
+    }
+
+    // A tile of the platform, a rock or the empty space `.`
+    pub(crate) fn label(rock: Option<Rock>) -> char {
+        match rock {
+            Some(Rock::Moving) => 'O',
+            Some(Rock::Standing) => '#',
+            None => '.',
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rocks = self.rocks.map(|rock| Rock::label(*rock));
+        write!(f, "{rocks}")
+    }
+}
+
+params! {
+    // Spin cycles in part 2
+    pub struct Day14Params {
+        cycles: usize = 1_000_000_000,
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Platform> {
+        let rocks = Grid::parse(input, "`O`, `#` or `.`", |c| match c {
+            '.' => Some(None),
+            c => Rock::to_rock(c).map(Some),
+        })?;
+
+        Ok(Platform { rocks })
+    }
+
+    fn part1(platform: &Platform) -> Result<usize> {
+        Self::part1_with(platform, &Day14Params::default())
+    }
+
+    fn part2(platform: &Platform) -> Result<usize> {
+        Self::part2_with(platform, &Day14Params::default())
+    }
+}
+
+impl Configurable for Day14 {
+    type Params = Day14Params;
+
+    fn part1_with(platform: &Platform, _: &Day14Params) -> Result<usize> {
+        let mut tilted_plat = platform.clone();
+        tilted_plat.tilt(Dir4::North);
+
+        Ok(tilted_plat.load())
+    }
+
+    fn part2_with(platform: &Platform, params: &Day14Params) -> Result<usize> {
+        let mut cycle = 0;
+
+        let mut tilted_plat = platform.clone();
+
+        let mut loops = HashMap::new();
+        loops.insert(tilted_plat.clone(), cycle);
+
+        // Once a platform comes back the spins repeat, so only the cycles past the last full loop are left
+        while cycle < params.cycles {
+            cycle += 1;
+            tilted_plat.spin_cycle();
+            trace!(trace::STEP, "spin", "cycle": cycle, "load": tilted_plat.load());
+
+            if let Some(start) = loops.insert(tilted_plat.clone(), cycle) {
+                let phase = (params.cycles - cycle) % (cycle - start);
+                trace!(trace::MILESTONE, "repeat", "cycle": cycle, "first": start, "left": phase);
+                for _ in 0..phase {
+                    tilted_plat.spin_cycle();
+                }
+                break;
+            }
+        }
+
+        Ok(tilted_plat.load())
+    }
+}
+
+impl Print for Day14 {
+    fn print(platform: &Platform) -> String {
+        platform.to_string()
+    }
+}
+
+/* --- Day 14: Parabolic Reflector Dish ---
+You reach the place where all of the mirrors were pointing: a massive parabolic reflector dish attached to the side of another large mountain.
+
+The dish is made up of many small mirrors, but while the mirrors themselves are roughly in the shape of a parabolic reflector dish, each individual mirror seems to be pointing in slightly the wrong direction. If the dish is meant to focus light, all it's doing right now is sending it in a vague direction.
+
+This system must be what provides the energy for the lava! If you focus the reflector dish, maybe you can go where it's pointing and use the light to fix the lava production.
+
+Upon closer inspection, the individual mirrors each appear to be connected via an elaborate system of ropes and pulleys to a large metal platform below the dish. The platform is covered in large rocks of various shapes. Depending on their position, the weight of the rocks deforms the platform, and the shape of the platform controls which ropes move and ultimately the focus of the dish.
+
+In short: if you move the rocks, you can focus the dish. The platform even has a control panel on the side that lets you tilt it in one of four directions! The rounded rocks (O) will roll when the platform is tilted, while the cube-shaped rocks (#) will stay in place. You note the positions of all of the empty spaces (.) and rocks (your puzzle input). For example:
+
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+Start by tilting the lever so all of the rocks will slide north as far as they will go:
+
+OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....
+You notice that the support beams along the north side of the platform are damaged; to ensure the platform doesn't collapse, you should calculate the total load on the north support beams.
+
+The amount of load caused by a single rounded rock (O) is equal to the number of rows from the rock to the south edge of the platform, including the row the rock is on. (Cube-shaped rocks (#) don't contribute to load.) So, the amount of load caused by each rock in each row is as follows:
+
+OOOO.#.O.. 10
+OO..#....#  9
+OO..O##..O  8
+O..#.OO...  7
+........#.  6
+..#....#.#  5
+..O..#.O.O  4
+..O.......  3
+#....###..  2
+#....#....  1
+The total load is the sum of the load caused by all of the rounded rocks. In this example, the total load is 136.
+
+Tilt the platform so that the rounded rocks all roll north. Afterward, what is the total load on the north support beams? 
+
+--- Part Two ---
+The parabolic reflector dish deforms, but not in a way that focuses the beam. To do that, you'll need to move the rocks to the edges of the platform. Fortunately, a button on the side of the control panel labeled "spin cycle" attempts to do just that!
+
+Each cycle tilts the platform four times so that the rounded rocks roll north, then west, then south, then east. After each tilt, the rounded rocks roll as far as they can before the platform tilts in the next direction. After one cycle, the platform will have finished rolling the rounded rocks in those four directions in that order.
+
+Here's what happens in the example above after each of the first few cycles:
+
+After 1 cycle:
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+
+After 2 cycles:
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O
+
+After 3 cycles:
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O
+This process should work if you leave it running long enough, but you're still worried about the north support beams. To make sure they'll survive for a while, you need to calculate the total load on the north support beams after 1000000000 cycles.
+
+In the above example, after 1000000000 cycles, the total load on the north support beams is 64.
+
+Run the spin cycle for 1000000000 cycles. Afterward, what is the total load on the north support beams?*/
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use aoc_common::{render::{Color, Frame, Simulate, Stepper, Style, Visualise}, Dir4};
+
+use crate::{Day14, Platform, Rock, SPIN};
+
+// The platform as given, tilted north for part 1, then after every spin cycle until it repeats
+impl Visualise for Day14 {
+    fn frames(platform: &Platform) -> Vec<Frame> {
+        let mut tilted = platform.clone();
+        tilted.tilt(Dir4::North);
+        let mut frames = vec![frame("As given", platform), frame(&format!("Tilted north, load {}", tilted.load()), &tilted)];
+
+        let mut spun = platform.clone();
+        let mut seen = HashSet::from([spun.clone()]);
+        for cycle in 1.. {
+            spun.spin_cycle();
+            frames.push(frame(&format!("After {cycle} spin cycles, load {}", spun.load()), &spun));
+            if !seen.insert(spun.clone()) {
+                break;
+            }
+        }
+
+        frames
+    }
+}
+
+// One tilt per step, in spin cycle order
+impl Simulate for Day14 {
+    fn stepper(platform: Platform) -> Box<dyn Stepper> {
+        Box::new(Tilts { platform, tilts: 0 })
+    }
+}
+
+struct Tilts {
+    platform: Platform,
+    tilts: usize,
+}
+
+impl Stepper for Tilts {
+    fn step(&mut self) -> bool {
+        self.platform.tilt(SPIN[self.tilts % SPIN.len()]);
+        self.tilts += 1;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let caption = match self.tilts {
+            0 => "As given".to_string(),
+            n => format!("Tilted {} in spin cycle {}, load {}", SPIN[(n - 1) % SPIN.len()], n.div_ceil(SPIN.len()), self.platform.load()),
+        };
+        frame(&caption, &self.platform)
+    }
+
+    fn cycle(&self) -> usize {
+        SPIN.len()
+    }
+}
+
+fn frame(caption: &str, platform: &Platform) -> Frame {
+    Frame::new(caption, &platform.rocks, |_, &rock| {
+        let color = match rock {
+            Some(Rock::Moving) => Color::ORANGE,
+            Some(Rock::Standing) => Color::WHITE,
+            None => Color::GREY,
+        };
+        Style::new(Rock::label(rock), color)
+    })
+}
@@ -0,0 +1,25 @@
+use advent_14_parabolic_reflector_dish::{Day14, Day14Params};
+use aoc_common::testing::{part1, part2, part2_with};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day14>(EXAMPLE), "136");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day14>(EXAMPLE), "64");
+}
+
+// From the third cycle on the example platform comes back every seven
+#[test]
+fn part2_any_number_of_cycles() {
+    let load = |cycles| part2_with::<Day14>(EXAMPLE, &Day14Params { cycles });
+
+    assert_eq!(load(0), "104");
+    for cycles in 3..20 {
+        assert_eq!(load(cycles), load(cycles + 7 * 1000), "{cycles} cycles");
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_common::Rng;
+
+// `size` steps on a one line initialization sequence, removing and relabeling lenses from a shared pool of labels
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let labels = (0..size.div_ceil(4).max(1)).map(|_| label(&mut rng)).collect::<Vec<_>>();
+
+    let steps = (0..size).map(|_| {
+        let label = rng.choose(&labels);
+        if rng.chance(0.3) {
+            format!("{label}-")
+        } else {
+            format!("{label}={}", rng.range(1..=9))
+        }
+    }).collect::<Vec<_>>();
+
+    steps.join(",") + "\n"
+}
+
+fn label(rng: &mut Rng) -> String {
+    (0..rng.range(2..=6)).map(|_| (b'a' + rng.below(26) as u8) as char).collect()
+}
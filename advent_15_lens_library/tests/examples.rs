@@ -0,0 +1,28 @@
+use advent_15_lens_library::Day15;
+use aoc_common::{testing::{part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day15>(EXAMPLE), "1320");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day15>(EXAMPLE), "145");
+}
+
+// The newline ending the file isn't part of the last step
+#[test]
+fn trailing_newline_is_not_hashed() {
+    assert_eq!(part1::<Day15>("rn=1,cm-\n"), "283");
+    assert_eq!(part1::<Day15>("rn=1,cm-\r\n"), "283");
+}
+
+#[test]
+fn steps_without_an_operation_are_an_error() {
+    let error = Day15::parse("rn=1,cm,qp=3").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 1, column 6: expected a step like `rn=1` or `cm-`, found `cm`");
+}
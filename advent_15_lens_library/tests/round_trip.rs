@@ -0,0 +1,12 @@
+use advent_15_lens_library::{generate::generate, Day15};
+use aoc_common::{testing::{parse, round_trip}, Print};
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day15>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
+
+#[test]
+fn trailing_newline_is_not_printed() {
+    assert_eq!(Day15::print(&parse::<Day15>("rn=1,cm-\n")), "rn=1,cm-");
+}
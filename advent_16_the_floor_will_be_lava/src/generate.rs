@@ -0,0 +1,14 @@
+use aoc_common::{Grid, Rng};
+
+const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+// A `size`x`size` contraption, about one tile in ten a mirror or a splitter
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let contraption = Grid::new(size, size, (0..size * size)
+        .map(|_| if rng.chance(0.1) { *rng.choose(&DEVICES) } else { '.' })
+        .collect());
+
+    contraption.to_string() + "\n"
+}
@@ -0,0 +1,258 @@
+pub mod generate;
+pub mod visualise;
+
+use std::{collections::HashSet, fmt, mem};
+use aoc_common::{trace, Dir4, Grid, Pos, Print, Result, Solution, Source};
+
+// Beams are a position and the direction they travel in
+#[derive(PartialEq)]
+pub struct Map {
+    pub map: Grid<Type>,
+}
+
+impl Map {
+    fn get_score(&self, start: (Pos, Dir4)) -> usize {
+        let score = self.energised(start).len();
+        trace!(trace::MILESTONE, "entry", "at": [start.0.0, start.0.1], "heading": start.1.to_string(), "energised": score);
+        score
+    }
+
+    // Tiles the beam entering at `start` passes through
+    fn energised(&self, start: (Pos, Dir4)) -> HashSet<Pos> {
+        let mut beams = Beams::new(start);
+        while beams.step(self) {}
+
+        beams.energised()
+    }
+
+    // Where a beam heading `dir` goes on from the tile at `pos`
+    fn redirect(&self, pos: Pos, dir: Dir4) -> Vec<Dir4> {
+        let new_dirs = match self.map[pos] {
+            Type::SlashMirror if dir.is_vertical() => vec![dir.turn_right()],
+            Type::SlashMirror => vec![dir.turn_left()],
+            Type::BackWardSlashMirror if dir.is_vertical() => vec![dir.turn_left()],
+            Type::BackWardSlashMirror => vec![dir.turn_right()],
+            Type::VerticalSplitter if !dir.is_vertical() => vec![dir.turn_left(), dir.turn_right()],
+            Type::HorizontalSplitter if dir.is_vertical() => vec![dir.turn_left(), dir.turn_right()],
+            _ => vec![dir],
+        };
+        if new_dirs != [dir] {
+            trace!(trace::STEP, "redirect", "at": [pos.0, pos.1], "from": dir.to_string(), "to": new_dirs.iter().map(Dir4::to_string).collect::<Vec<_>>());
+        }
+
+        new_dirs
+    }
+
+    // Every tile on the edge with the beam heading away from that edge
+    fn entries(&self) -> Vec<(Pos, Dir4)> {
+        let (rows, cols) = (self.map.rows(), self.map.cols());
+
+        let mut entries = Vec::new();
+        for y in 0..cols {
+            entries.push(((0, y), Dir4::South));
+            entries.push(((rows - 1, y), Dir4::North));
+        }
+        for x in 0..rows {
+            entries.push(((x, 0), Dir4::East));
+            entries.push(((x, cols - 1), Dir4::West));
+        }
+
+        entries
+    }
+}
+
+// The tips of the beams, one tile further every step. A beam stops where one went the same way before
+struct Beams {
+    front: Vec<(Pos, Dir4)>,
+    visited: HashSet<(Pos, Dir4)>,
+}
+
+impl Beams {
+    fn new(start: (Pos, Dir4)) -> Beams {
+        Beams { front: vec![start], visited: HashSet::from([start]) }
+    }
+
+    // False once every beam left the map or stopped
+    fn step(&mut self, map: &Map) -> bool {
+        if self.front.is_empty() {
+            return false;
+        }
+
+        let mut front = Vec::new();
+        for (pos, dir) in mem::take(&mut self.front) {
+            for new_dir in map.redirect(pos, dir) {
+                if let Some(new_pos) = map.map.step(pos, new_dir) {
+                    if self.visited.insert((new_pos, new_dir)) {
+                        front.push((new_pos, new_dir));
+                    }
+                }
+            }
+        }
+        self.front = front;
+        true
+    }
+
+    fn energised(&self) -> HashSet<Pos> {
+        self.visited.iter().map(|x| x.0).collect::<HashSet<Pos>>()
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Type {
+    SlashMirror,
+    BackWardSlashMirror,
+    VerticalSplitter,
+    HorizontalSplitter,
+    Empty
+}
+
+impl Type {
+    fn new(c: char) -> Option<Type> {
+        match c {
+            '/' => Some(Type::SlashMirror),
+            '\\' => Some(Type::BackWardSlashMirror),
+            '|' => Some(Type::VerticalSplitter),
+            '-' => Some(Type::HorizontalSplitter),
+            '.' => Some(Type::Empty),
+            _ => None
+        }
+    }
+
+    pub(crate) fn label(&self) -> char {
+        match self {
+            Type::SlashMirror => '/',
+            Type::BackWardSlashMirror => '\\',
+            Type::VerticalSplitter => '|',
+            Type::HorizontalSplitter => '-',
+            Type::Empty => '.',
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Map> {
+        let map = Grid::parse(input, "a mirror, splitter or `.`", Type::new)?;
+        // The beam of part 1 enters at the top left tile
+        if map.rows() == 0 || map.cols() == 0 {
+            return Err(Source::new(input).missing(input, "a mirror, splitter or `.`").into());
+        }
+
+        Ok(Map { map })
+    }
+
+    fn part1(map: &Map) -> Result<usize> {
+        let score = map.get_score(((0, 0), Dir4::East));
+        Ok(score)
+    }
+
+    fn part2(map: &Map) -> Result<usize> {
+        let max_score = map.entries().into_iter().map(|start| map.get_score(start)).max().unwrap_or(0);
+        Ok(max_score)
+    }
+}
+
+impl Print for Day16 {
+    fn print(map: &Map) -> String {
+        map.map.to_string()
+    }
+}
+
+/* --- Day 16: The Floor Will Be Lava ---
+With the beam of light completely focused somewhere, the reindeer leads you deeper still into the Lava Production Facility. At some point, you realize that the steel facility walls have been replaced with cave, and the doorways are just cave, and the floor is cave, and you're pretty sure this is actually just a giant cave.
+
+Finally, as you approach what must be the heart of the mountain, you see a bright light in a cavern up ahead. There, you discover that the beam of light you so carefully focused is emerging from the cavern wall closest to the facility and pouring all of its energy into a contraption on the opposite side.
+
+Upon closer inspection, the contraption appears to be a flat, two-dimensional square grid containing empty space (.), mirrors (/ and \), and splitters (| and -).
+
+The contraption is aligned so that most of the beam bounces around the grid, but each tile on the grid converts some of the beam's light into heat to melt the rock in the cavern.
+
+You note the layout of the contraption (your puzzle input). For example:
+
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+The beam enters in the top-left corner from the left and heading to the right. Then, its behavior depends on what it encounters as it moves:
+
+If the beam encounters empty space (.), it continues in the same direction.
+If the beam encounters a mirror (/ or \), the beam is reflected 90 degrees depending on the angle of the mirror. For instance, a rightward-moving beam that encounters a / mirror would continue upward in the mirror's column, while a rightward-moving beam that encounters a \ mirror would continue downward from the mirror's column.
+If the beam encounters the pointy end of a splitter (| or -), the beam passes through the splitter as if the splitter were empty space. For instance, a rightward-moving beam that encounters a - splitter would continue in the same direction.
+If the beam encounters the flat side of a splitter (| or -), the beam is split into two beams going in each of the two directions the splitter's pointy ends are pointing. For instance, a rightward-moving beam that encounters a | splitter would split into two beams: one that continues upward from the splitter's column and one that continues downward from the splitter's column.
+Beams do not interact with other beams; a tile can have many beams passing through it at the same time. A tile is energized if that tile has at least one beam pass through it, reflect in it, or split in it.
+
+In the above example, here is how the beam of light bounces around the contraption:
+
+>|<<<\....
+|v-.\^....
+.v...|->>>
+.v...v^.|.
+.v...v^...
+.v...v^..\
+.v../2\\..
+<->-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+Beams are only shown on empty tiles; arrows indicate the direction of the beams. If a tile contains beams moving in multiple directions, the number of distinct directions is shown instead. Here is the same diagram but instead only showing whether a tile is energized (#) or not (.):
+
+######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..
+Ultimately, in this example, 46 tiles become energized.
+
+The light isn't energizing enough tiles to produce lava; to debug the contraption, you need to start by analyzing the current situation. With the beam starting in the top-left heading right, how many tiles end up being energized?
+
+--- Part Two ---
+As you try to work out what might be wrong, the reindeer tugs on your shirt and leads you to a nearby control panel. There, a collection of buttons lets you align the contraption so that the beam enters from any edge tile and heading away from that edge. (You can choose either of two directions for the beam if it starts on a corner; for instance, if the beam starts in the bottom-right corner, it can start heading either left or upward.)
+
+So, the beam could start on any tile in the top row (heading downward), any tile in the bottom row (heading upward), any tile in the leftmost column (heading right), or any tile in the rightmost column (heading left). To produce lava, you need to find the configuration that energizes as many tiles as possible.
+
+In the above example, this can be achieved by starting the beam in the fourth tile from the left in the top row:
+
+.|<2<\....
+|v-v\^....
+.v.v.|->>>
+.v.v.v^.|.
+.v.v.v^...
+.v.v.v^..\
+.v.v/2\\..
+<-2-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+Using this configuration, 51 tiles are energized:
+
+.#####....
+.#.#.#....
+.#.#.#####
+.#.#.##...
+.#.#.##...
+.#.#.##...
+.#.#####..
+########..
+.#######..
+.#...#.#..
+Find the initial beam configuration that energizes the largest number of tiles; how many tiles are energized in that configuration? */
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{render::{Color, Frame, Simulate, Stepper, Style, Visualise}, Dir4, Pos};
+
+use crate::{Beams, Day16, Map, Type};
+
+// Energised tiles in orange, for the beam of part 1 and for the best entry of part 2
+impl Visualise for Day16 {
+    fn frames(map: &Map) -> Vec<Frame> {
+        let best = map.entries().into_iter().max_by_key(|&start| map.get_score(start));
+
+        [Some(((0, 0), Dir4::East)), best].into_iter().flatten().map(|start| {
+            let energised = map.energised(start);
+            let caption = format!("{} tiles energised from {:?} heading {}", energised.len(), start.0, start.1);
+            frame(&caption, map, &energised, &HashMap::new())
+        }).collect()
+    }
+}
+
+// The beam of part 1 spreading a tile per step, its tips drawn as arrows
+impl Simulate for Day16 {
+    fn stepper(map: Map) -> Box<dyn Stepper> {
+        Box::new(Spread { map, beams: Beams::new(((0, 0), Dir4::East)) })
+    }
+}
+
+struct Spread {
+    map: Map,
+    beams: Beams,
+}
+
+impl Stepper for Spread {
+    fn step(&mut self) -> bool {
+        self.beams.step(&self.map)
+    }
+
+    fn frame(&self) -> Frame {
+        let energised = self.beams.energised();
+        let tips = self.beams.front.iter().map(|&(pos, dir)| (pos, dir.arrow())).collect::<HashMap<_, _>>();
+        let caption = format!("{} beams moving, {} tiles energised", self.beams.front.len(), energised.len());
+        frame(&caption, &self.map, &energised, &tips)
+    }
+}
+
+fn frame(caption: &str, map: &Map, energised: &HashSet<Pos>, tips: &HashMap<Pos, char>) -> Frame {
+    Frame::new(caption, &map.map, |pos, tile| {
+        if let Some(&arrow) = tips.get(&pos) {
+            return Style::new(arrow, Color::RED);
+        }
+
+        let glyph = match tile {
+            Type::Empty if energised.contains(&pos) => '#',
+            _ => tile.label(),
+        };
+
+        match (energised.contains(&pos), tile) {
+            (true, Type::Empty) => Style::new(glyph, Color::ORANGE),
+            (true, _) => Style::new(glyph, Color::YELLOW),
+            (false, Type::Empty) => Style::new(glyph, Color::GREY),
+            (false, _) => Style::new(glyph, Color::WHITE),
+        }
+    })
+}
@@ -0,0 +1,21 @@
+use advent_16_the_floor_will_be_lava::Day16;
+use aoc_common::{testing::{part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day16>(EXAMPLE), "46");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day16>(EXAMPLE), "51");
+}
+
+#[test]
+fn empty_maps_are_an_error() {
+    let error = Day16::parse("").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 1, column 1: expected a mirror, splitter or `.`, found end of line");
+}
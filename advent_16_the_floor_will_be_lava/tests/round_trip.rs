@@ -0,0 +1,7 @@
+use advent_16_the_floor_will_be_lava::{generate::generate, Day16};
+use aoc_common::testing::round_trip;
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day16>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
@@ -0,0 +1,14 @@
+use aoc_common::{Grid, Rng};
+
+// A `size`x`size` map of heat loss digits
+pub fn generate(seed: u64, size: usize) -> String {
+    grid(seed, size, size)
+}
+
+// Any dimensions work for part 1, part 2 needs room for a run of four blocks, at least 5x5
+pub fn grid(seed: u64, rows: usize, cols: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let blocks = Grid::new(rows, cols, (0..rows * cols).map(|_| rng.range(1..=9)).collect());
+
+    blocks.to_string() + "\n"
+}
@@ -0,0 +1,214 @@
+pub mod generate;
+pub mod visualise;
+
+use std::hash::Hash;
+use aoc_common::{params, search::{dijkstra, Graph}, trace, Configurable, Dir4, Grid, Pos, Print, Result, Solution};
+
+// Coords, the direction of the last move (none before the first) and how many moves in a row went that way
+#[derive(PartialEq, Eq, Hash, Clone, PartialOrd, Ord, Debug)]
+struct Node(Pos, Option<Dir4>, usize);
+
+params! {
+    // Blocks the crucible of part 1 and the ultra crucible of part 2 move at least and at most in a line
+    pub struct Day17Params {
+        crucible_min: usize = 0,
+        crucible_max: usize = 3,
+        ultra_min: usize = 4,
+        ultra_max: usize = 10,
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Grid<i64>> {
+        let nodes = Grid::parse(input, "a heat loss digit", |ch| ch.to_digit(10).map(i64::from))?;
+        Ok(nodes)
+    }
+
+    fn part1(nodes: &Grid<i64>) -> Result<i64> {
+        Self::part1_with(nodes, &Day17Params::default())
+    }
+
+    fn part2(nodes: &Grid<i64>) -> Result<i64> {
+        Self::part2_with(nodes, &Day17Params::default())
+    }
+}
+
+impl Configurable for Day17 {
+    type Params = Day17Params;
+
+    fn part1_with(nodes: &Grid<i64>, params: &Day17Params) -> Result<i64> {
+        min_heat_loss(nodes, params.crucible_min, params.crucible_max)
+    }
+
+    fn part2_with(nodes: &Grid<i64>, params: &Day17Params) -> Result<i64> {
+        min_heat_loss(nodes, params.ultra_min, params.ultra_max)
+    }
+}
+
+impl Print for Day17 {
+    fn print(nodes: &Grid<i64>) -> String {
+        nodes.to_string()
+    }
+}
+
+// The crucible has to go at least `min_steps` before turning or stopping and at most `max_steps` in a line
+fn min_heat_loss(nodes: &Grid<i64>, min_steps: usize, max_steps: usize) -> Result<i64> {
+    if max_steps < 1 || min_steps > max_steps {
+        return Err(format!("A crucible can't move at least {min_steps} and at most {max_steps} blocks in a line").into());
+    }
+
+    let (route, heat_loss) = best_route(nodes, min_steps, max_steps).ok_or("No path to the bottom right block")?;
+    for (from, to) in route.iter().zip(&route[1..]).filter(|(from, to)| from.1 != to.1) {
+        trace!(trace::STEP, "turn", "at": [from.0.0, from.0.1], "heading": to.1.map(|dir| dir.to_string()), "straight": from.2);
+    }
+    Ok(heat_loss)
+}
+
+// Moves of the crucible, entering a block costs its heat loss
+struct Crucible<'a> {
+    nodes: &'a Grid<i64>,
+    min_steps: usize,
+    max_steps: usize,
+}
+
+impl Graph for Crucible<'_> {
+    type Node = Node;
+
+    fn neighbours(&self, node: &Node) -> Vec<(Node, usize)> {
+        let steps = node.2;
+
+        let mut neighbors = Vec::new();
+        for dir in Dir4::ALL {
+            let next_steps = match node.1 {
+                None => 1,
+                Some(last) if last == dir && steps < self.max_steps => steps + 1,
+                Some(last) if (last == dir.turn_left() || last == dir.turn_right()) && steps >= self.min_steps => 1,
+                _ => continue,
+            };
+
+            if let Some(pos) = self.nodes.step(node.0, dir) {
+                neighbors.push((Node(pos, Some(dir), next_steps), self.nodes[pos] as usize));
+            }
+        }
+
+        neighbors
+    }
+
+    // Blocks in reading order, then no direction or one of four, then steps
+    fn index(&self, Node((row, col), dir, steps): &Node) -> Option<usize> {
+        let block = row * self.nodes.cols() + col;
+        let dir = dir.map_or(0, |dir| dir as usize + 1);
+        Some((block * 5 + dir) * (self.max_steps + 1) + steps)
+    }
+}
+
+// Every block the crucible passes through from the top left on, and the heat lost on the way
+fn best_route(nodes: &Grid<i64>, min_steps: usize, max_steps: usize) -> Option<(Vec<Node>, i64)> {
+    let start = Node((0, 0), None, 0);
+    let goal = (nodes.rows().saturating_sub(1), nodes.cols().saturating_sub(1));
+
+    let crucible = Crucible { nodes, min_steps, max_steps };
+    let (route, heat_loss) = dijkstra(&crucible, start, |node| node.0 == goal && node.2 >= min_steps)?;
+    Some((route, heat_loss as i64))
+}
+
+/*--- Day 17: Clumsy Crucible ---
+The lava starts flowing rapidly once the Lava Production Facility is operational. As you leave, the reindeer offers you a parachute, allowing you to quickly reach Gear Island.
+
+As you descend, your bird's-eye view of Gear Island reveals why you had trouble finding anyone on your way up: half of Gear Island is empty, but the half below you is a giant factory city!
+
+You land near the gradually-filling pool of lava at the base of your new lavafall. Lavaducts will eventually carry the lava throughout the city, but to make use of it immediately, Elves are loading it into large crucibles on wheels.
+
+The crucibles are top-heavy and pushed by hand. Unfortunately, the crucibles become very difficult to steer at high speeds, and so it can be hard to go in a straight line for very long.
+
+To get Desert Island the machine parts it needs as soon as possible, you'll need to find the best way to get the crucible from the lava pool to the machine parts factory. To do this, you need to minimize heat loss while choosing a route that doesn't require the crucible to go in a straight line for too long.
+
+Fortunately, the Elves here have a map (your puzzle input) that uses traffic patterns, ambient temperature, and hundreds of other parameters to calculate exactly how much heat loss can be expected for a crucible entering any particular city block.
+
+For example:
+
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+Each city block is marked by a single digit that represents the amount of heat loss if the crucible enters that block. The starting point, the lava pool, is the top-left city block; the destination, the machine parts factory, is the bottom-right city block. (Because you already start in the top-left block, you don't incur that block's heat loss unless you leave that block and then return to it.)
+
+Because it is difficult to keep the top-heavy crucible going in a straight line for very long, it can move at most three blocks in a single direction before it must turn 90 degrees left or right. The crucible also can't reverse direction; after entering each city block, it may only turn left, continue straight, or turn right.
+
+One way to minimize heat loss is this path:
+
+2>>34^>>>1323
+32v>>>35v5623
+32552456v>>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>
+This path never moves more than three consecutive blocks in the same direction and incurs a heat loss of only 102.
+
+Directing the crucible from the lava pool to the machine parts factory, but not moving more than three consecutive blocks in the same direction, what is the least heat loss it can incur?
+
+--- Part Two ---
+The crucibles of lava simply aren't large enough to provide an adequate supply of lava to the machine parts factory. Instead, the Elves are going to upgrade to ultra crucibles.
+
+Ultra crucibles are even more difficult to steer than normal crucibles. Not only do they have trouble going in a straight line, but they also have trouble turning!
+
+Once an ultra crucible starts moving in a direction, it needs to move a minimum of four blocks in that direction before it can turn (or even before it can stop at the end). However, it will eventually start to get wobbly: an ultra crucible can move a maximum of ten consecutive blocks without turning.
+
+In the above example, an ultra crucible could follow this path to minimize heat loss:
+
+2>>>>>>>>1323
+32154535v5623
+32552456v4254
+34465858v5452
+45466578v>>>>
+143859879845v
+445787698776v
+363787797965v
+465496798688v
+456467998645v
+122468686556v
+254654888773v
+432267465553v
+In the above example, an ultra crucible would incur the minimum possible heat loss of 94.
+
+Here's another example:
+
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+Sadly, an ultra crucible would need to take an unfortunate path like this one:
+
+1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+This route causes the ultra crucible to incur the minimum possible heat loss of 71.
+
+Directing the ultra crucible from the lava pool to the machine parts factory, what is the least heat loss it can incur?
+
+Your puzzle answer was 1027. */
\ No newline at end of file
@@ -1,230 +1,6 @@
-use pathfinding::prelude::dijkstra;
-use std::{collections::HashMap, fs, hash::Hash};
-
-pub type Error = Box<dyn std::error::Error>;
-pub type Result<T> = std::result::Result<T, Error>;
-
-// Coords, dir, steps
-//dir: up, down, left, right
-#[derive(PartialEq, Eq, Hash, Clone, PartialOrd, Ord, Debug)]
-struct Node(Coord, i64, i64);
-
-#[derive(PartialEq, Eq, Hash, Clone, PartialOrd, Ord, Debug)]
-struct Coord(i64, i64);
+use advent_17_clumsy_crucible::Day17;
+use aoc_common::{solution, Result};
 
 fn main() -> Result<()> {
-    let nodes = parse()?;
-    let max_x = nodes.iter().max_by(|a, b| a.0.0.cmp(&b.0.0)).unwrap().0.0;
-    let max_y = nodes.iter().max_by(|a, b| a.0.1.cmp(&b.0.1)).unwrap().0.1;
-
-    part1(&nodes, max_x, max_y)?;
-    part2(&nodes, max_x, max_y)?;
-
-    return Ok(());
-}
-
-fn parse() -> Result<HashMap<Coord, i64>> {
-    let input = fs::read_to_string("input.txt")?;
-
-    let mut nodes: HashMap<Coord, i64> = HashMap::new();
-    for (x, line) in input.lines().enumerate() {
-        for (y, ch) in line.chars().enumerate() {
-            nodes.insert(Coord(x as i64, y as i64), ch.to_string().parse().unwrap());
-        }
-    }
-
-    Ok(nodes)
-}
-
-fn part1(nodes: &HashMap<Coord, i64>, max_x: i64, max_y: i64) -> Result<()> {
-    let start = Node(Coord(0, 0), 0, 0);
-    let goal = Coord(max_x, max_y);
-
-    let answer = dijkstra(&start, |node| {
-        let x = node.0.0;
-        let y = node.0.1;
-
-        let dir = node.1;
-        let steps = node.2;
-
-        let up_coord = Coord(x - 1, y);
-        let down_coord = Coord(x + 1, y);
-        let left_coord = Coord(x, y- 1);
-        let right_coord = Coord(x, y + 1);
-
-        let mut neighbors: Vec<(Node, i64)> = Vec::new();
-
-        if dir == 0 && steps < 3 {
-            neighbors.push((Node(up_coord.clone(), 0, steps + 1), *nodes.get(&up_coord).unwrap_or(&1000)))
-        } else if dir != 0 && dir != 1 {
-            neighbors.push((Node(up_coord.clone(), 0, 1), *nodes.get(&up_coord).unwrap_or(&1000)))
-        }
-
-        if dir == 1 && steps < 3 {
-            neighbors.push((Node(down_coord.clone(), 1, steps + 1), *nodes.get(&down_coord).unwrap_or(&1000)))
-        } else if dir != 1 && dir != 0 {
-            neighbors.push((Node(down_coord.clone(), 1, 1), *nodes.get(&down_coord).unwrap_or(&1000)))
-        }
-
-        if dir == 2 && steps < 3 {
-            neighbors.push((Node(left_coord.clone(), 2, steps + 1), *nodes.get(&left_coord).unwrap_or(&1000)))
-        } else if dir != 2 && dir != 3 {
-            neighbors.push((Node(left_coord.clone(), 2, 1), *nodes.get(&left_coord).unwrap_or(&1000)))
-        }
-
-        if dir == 3 && steps < 3 {
-            neighbors.push((Node(right_coord.clone(), 3, steps + 1), *nodes.get(&right_coord).unwrap_or(&1000)))
-        } else if dir != 3 && dir != 2 {
-            neighbors.push((Node(right_coord.clone(), 3, 1), *nodes.get(&right_coord).unwrap_or(&1000)))
-        }
-
-        neighbors
-    }, |node| node.0 == goal);
-
-    println!("Part 1 answer: {}", answer.iter().next().unwrap().1);
-    return Ok(());
+    solution::run::<Day17>()
 }
-
-fn part2(nodes: &HashMap<Coord, i64>, max_x: i64, max_y: i64) -> Result<()> {
-    let start = Node(Coord(0, 0), -1, 0);
-    let goal = Coord(max_x, max_y);
-
-    let answer = dijkstra(&start, |node| {
-        let x = node.0.0;
-        let y = node.0.1;
-
-        let dir = node.1;
-        let steps = node.2;
-
-        let up_coord = Coord(x - 1, y);
-        let down_coord = Coord(x + 1, y);
-        let left_coord = Coord(x, y- 1);
-        let right_coord = Coord(x, y + 1);
-
-        let mut neighbors: Vec<(Node, i64)> = Vec::new();
-
-        if (dir == 0 || dir == -1) && steps < 10 {
-            neighbors.push((Node(up_coord.clone(), 0, steps + 1), *nodes.get(&up_coord).unwrap_or(&1000)))
-        } else if dir != 0 && dir != 1 && steps > 3 {
-            neighbors.push((Node(up_coord.clone(), 0, 1), *nodes.get(&up_coord).unwrap_or(&1000)))
-        }
-
-        if (dir == 1 || dir == -1) && steps < 10 {
-            neighbors.push((Node(down_coord.clone(), 1, steps + 1), *nodes.get(&down_coord).unwrap_or(&1000)))
-        } else if dir != 1 && dir != 0 && steps > 3 {
-            neighbors.push((Node(down_coord.clone(), 1, 1), *nodes.get(&down_coord).unwrap_or(&1000)))
-        }
-
-        if (dir == 2 || dir == -1) && steps < 10 {
-            neighbors.push((Node(left_coord.clone(), 2, steps + 1), *nodes.get(&left_coord).unwrap_or(&1000)))
-        } else if dir != 2 && dir != 3 && steps > 3 {
-            neighbors.push((Node(left_coord.clone(), 2, 1), *nodes.get(&left_coord).unwrap_or(&1000)))
-        }
-
-        if (dir == 3 || dir == -1) && steps < 10 {
-            neighbors.push((Node(right_coord.clone(), 3, steps + 1), *nodes.get(&right_coord).unwrap_or(&1000)))
-        } else if dir != 3 && dir != 2 && steps > 3 {
-            neighbors.push((Node(right_coord.clone(), 3, 1), *nodes.get(&right_coord).unwrap_or(&1000)))
-        }
-
-        neighbors
-    }, |node| node.0 == goal && node.2 > 3);
-
-    println!("Part 2 answer: {}", answer.iter().next().unwrap().1);
-    return Ok(());
-}
-
-/*--- Day 17: Clumsy Crucible ---
-The lava starts flowing rapidly once the Lava Production Facility is operational. As you leave, the reindeer offers you a parachute, allowing you to quickly reach Gear Island.
-
-As you descend, your bird's-eye view of Gear Island reveals why you had trouble finding anyone on your way up: half of Gear Island is empty, but the half below you is a giant factory city!
-
-You land near the gradually-filling pool of lava at the base of your new lavafall. Lavaducts will eventually carry the lava throughout the city, but to make use of it immediately, Elves are loading it into large crucibles on wheels.
-
-The crucibles are top-heavy and pushed by hand. Unfortunately, the crucibles become very difficult to steer at high speeds, and so it can be hard to go in a straight line for very long.
-
-To get Desert Island the machine parts it needs as soon as possible, you'll need to find the best way to get the crucible from the lava pool to the machine parts factory. To do this, you need to minimize heat loss while choosing a route that doesn't require the crucible to go in a straight line for too long.
-
-Fortunately, the Elves here have a map (your puzzle input) that uses traffic patterns, ambient temperature, and hundreds of other parameters to calculate exactly how much heat loss can be expected for a crucible entering any particular city block.
-
-For example:
-
-2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533
-Each city block is marked by a single digit that represents the amount of heat loss if the crucible enters that block. The starting point, the lava pool, is the top-left city block; the destination, the machine parts factory, is the bottom-right city block. (Because you already start in the top-left block, you don't incur that block's heat loss unless you leave that block and then return to it.)
-
-Because it is difficult to keep the top-heavy crucible going in a straight line for very long, it can move at most three blocks in a single direction before it must turn 90 degrees left or right. The crucible also can't reverse direction; after entering each city block, it may only turn left, continue straight, or turn right.
-
-One way to minimize heat loss is this path:
-
-2>>34^>>>1323
-32v>>>35v5623
-32552456v>>54
-3446585845v52
-4546657867v>6
-14385987984v4
-44578769877v6
-36378779796v>
-465496798688v
-456467998645v
-12246868655<v
-25465488877v5
-43226746555v>
-This path never moves more than three consecutive blocks in the same direction and incurs a heat loss of only 102.
-
-Directing the crucible from the lava pool to the machine parts factory, but not moving more than three consecutive blocks in the same direction, what is the least heat loss it can incur?
-
---- Part Two ---
-The crucibles of lava simply aren't large enough to provide an adequate supply of lava to the machine parts factory. Instead, the Elves are going to upgrade to ultra crucibles.
-
-Ultra crucibles are even more difficult to steer than normal crucibles. Not only do they have trouble going in a straight line, but they also have trouble turning!
-
-Once an ultra crucible starts moving in a direction, it needs to move a minimum of four blocks in that direction before it can turn (or even before it can stop at the end). However, it will eventually start to get wobbly: an ultra crucible can move a maximum of ten consecutive blocks without turning.
-
-In the above example, an ultra crucible could follow this path to minimize heat loss:
-
-2>>>>>>>>1323
-32154535v5623
-32552456v4254
-34465858v5452
-45466578v>>>>
-143859879845v
-445787698776v
-363787797965v
-465496798688v
-456467998645v
-122468686556v
-254654888773v
-432267465553v
-In the above example, an ultra crucible would incur the minimum possible heat loss of 94.
-
-Here's another example:
-
-111111111111
-999999999991
-999999999991
-999999999991
-999999999991
-Sadly, an ultra crucible would need to take an unfortunate path like this one:
-
-1>>>>>>>1111
-9999999v9991
-9999999v9991
-9999999v9991
-9999999v>>>>
-This route causes the ultra crucible to incur the minimum possible heat loss of 71.
-
-Directing the ultra crucible from the lava pool to the machine parts factory, what is the least heat loss it can incur?
-
-Your puzzle answer was 1027. */
\ No newline at end of file
@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use aoc_common::{render::{Color, Frame, Style, Visualise}, Grid};
+
+use crate::{best_route, Day17, Day17Params};
+
+// The heat loss map with the best route of the crucible and of the ultra crucible drawn over it,
+// brighter blocks lose more heat
+impl Visualise for Day17 {
+    fn frames(nodes: &Grid<i64>) -> Vec<Frame> {
+        let params = Day17Params::default();
+        [("Crucible", params.crucible_min, params.crucible_max), ("Ultra crucible", params.ultra_min, params.ultra_max)].into_iter().map(|(name, min_steps, max_steps)| {
+            let Some((route, heat_loss)) = best_route(nodes, min_steps, max_steps) else {
+                return Frame::new(format!("{name}: no route"), nodes, |_, &loss| block(loss));
+            };
+
+            let arrows = route.iter().filter_map(|node| Some((node.0, node.1?.arrow()))).collect::<HashMap<_, _>>();
+            Frame::new(format!("{name}: heat loss {heat_loss}"), nodes, |pos, &loss| match arrows.get(&pos) {
+                Some(&arrow) => Style::new(arrow, Color::RED),
+                None => block(loss),
+            })
+        }).collect()
+    }
+}
+
+fn block(loss: i64) -> Style {
+    let shade = (40 + loss * 20) as u8;
+    Style::new(char::from_digit(loss as u32, 10).unwrap_or('?'), Color(shade, shade, shade))
+}
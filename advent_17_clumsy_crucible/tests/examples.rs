@@ -0,0 +1,20 @@
+use advent_17_clumsy_crucible::Day17;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE_1: &str = include_str!("../fixtures/example_1.txt");
+const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day17>(EXAMPLE_1), "102");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day17>(EXAMPLE_1), "94");
+}
+
+#[test]
+fn part2_long_straight_line() {
+    assert_eq!(part2::<Day17>(EXAMPLE_2), "71");
+}
@@ -0,0 +1,50 @@
+use std::cmp::Ordering;
+
+use aoc_common::{random, Rng};
+
+// A dig plan around a random blob of up to `size`x`size` cells, starting at any of its corners and going
+// either way round. The color codes trace the same outline, stretched to part 2 distances
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let cells = size.max(1);
+
+    let corners = random::simple_loop(&mut rng, cells);
+
+    // Only the corners where the trench turns become steps of the plan
+    let mut corners = (0..corners.len())
+        .filter(|&i| {
+            let (before, after) = (corners[(i + corners.len() - 1) % corners.len()], corners[(i + 1) % corners.len()]);
+            before.0 != after.0 && before.1 != after.1
+        })
+        .map(|i| corners[i])
+        .collect::<Vec<_>>();
+    let first = rng.below(corners.len());
+    corners.rotate_left(first);
+    if rng.chance(0.5) {
+        corners.reverse();
+    }
+
+    // Every row and column of cells gets its own width, down to 1 so trenches can run side by side
+    let widths = |rng: &mut Rng, max: u64| (0..=cells).map(|_| rng.range(1..=max) as i64).collect::<Vec<_>>();
+    let (rows, cols) = (widths(&mut rng, 10), widths(&mut rng, 10));
+    // Color codes only hold five hex digits
+    let far = (0xfffff / (cells as u64 + 1)).max(1);
+    let (big_rows, big_cols) = (widths(&mut rng, far), widths(&mut rng, far));
+
+    let mut plan = String::new();
+    for (i, &(row, col)) in corners.iter().enumerate() {
+        let (next_row, next_col) = corners[(i + 1) % corners.len()];
+        let (dir, code, from, to, lengths, big) = match (next_row.cmp(&row), next_col.cmp(&col)) {
+            (_, Ordering::Greater) => ('R', 0, col, next_col, &cols, &big_cols),
+            (Ordering::Greater, _) => ('D', 1, row, next_row, &rows, &big_rows),
+            (_, Ordering::Less) => ('L', 2, next_col, col, &cols, &big_cols),
+            _ => ('U', 3, next_row, row, &rows, &big_rows),
+        };
+
+        let steps = lengths[from..to].iter().sum::<i64>();
+        let far = big[from..to].iter().sum::<i64>();
+        plan += &format!("{dir} {steps} (#{far:05x}{code})\n");
+    }
+
+    plan
+}
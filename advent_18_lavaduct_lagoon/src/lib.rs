@@ -0,0 +1,233 @@
+pub mod generate;
+pub mod visualise;
+
+use std::{collections::{HashMap, HashSet}, fmt};
+use aoc_common::{search::{bfs, Graph}, trace, Coord, Dir4, ParseResult, Print, Result, Solution, Source};
+
+// Direction, Steps, Color Code
+#[derive(PartialEq)]
+pub struct Command(pub Dir4, pub i64, pub String);
+
+fn direction(c: &str) -> Option<Dir4> {
+    match c {
+        "U" => Some(Dir4::North),
+        "D" => Some(Dir4::South),
+        "L" => Some(Dir4::West),
+        "R" => Some(Dir4::East),
+        _ => None
+    }
+}
+
+// The last digit of a color code: 0 means R, 1 means D, 2 means L, and 3 means U
+fn direction_from_code(c: char) -> Option<Dir4> {
+    match c {
+        '0' => Some(Dir4::East),
+        '1' => Some(Dir4::South),
+        '2' => Some(Dir4::West),
+        '3' => Some(Dir4::North),
+        _ => None
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dir = match self.0 {
+            Dir4::North => 'U',
+            Dir4::South => 'D',
+            Dir4::West => 'L',
+            Dir4::East => 'R',
+        };
+        write!(f, "{dir} {} ({})", self.1, self.2)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Command>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        let source = Source::new(input);
+        let commands = input.lines().map(|line| {
+            let dir = source.token(line, 0, "a direction")?;
+            let dir = direction(dir).ok_or_else(|| source.unexpected(dir, "`U`, `D`, `L` or `R`"))?;
+            let steps = source.number(source.token(line, 1, "a step count")?)?;
+
+            // Part 2 reads the steps and direction out of the color code
+            let color = source.token(line, 2, "a color code")?.trim_matches(|c| c == '(' || c == ')');
+            let is_code = color.len() == 7 && color.starts_with('#') && color[1..].chars().all(|c| c.is_ascii_hexdigit()) && color.ends_with(['0', '1', '2', '3']);
+            if !is_code {
+                return Err(source.unexpected(color, "a color code like `#70c710`"));
+            }
+
+            Ok(Command(dir, steps, color.to_string()))
+        }).collect::<ParseResult<Vec<Command>>>()?;
+
+        Ok(commands)
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<usize> {
+        let score = get_score(commands);
+        Ok(score)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<i64> {
+        let mut prev = Coord::default();
+
+        let mut total = 0i64;
+        let mut perimeter = 0i64;
+        for Command(_, _, com) in commands {
+            let dir = direction_from_code(com.chars().last().unwrap()).expect("parsing checked the code");
+            let steps = i64::from_str_radix(&com[1..6], 16).unwrap();
+
+            let next = prev.step(dir, steps);
+            trace!(trace::STEP, "dig", "code": com, "heading": dir.to_string(), "steps": steps, "to": [next.row, next.col]);
+
+            perimeter += steps;
+            total += (prev.col * next.row) - (prev.row * next.col);
+            prev = next;
+        }
+
+        // The shoelace sum is negative for a plan dug counterclockwise
+        trace!(trace::MILESTONE, "shoelace", "area": total.abs() / 2, "perimeter": perimeter);
+        Ok((total.abs() + perimeter) / 2 + 1)
+    }
+}
+
+impl Print for Day18 {
+    fn print(commands: &Vec<Command>) -> String {
+        commands.iter().map(Command::to_string).collect::<Vec<_>>().join("\n")
+    }
+}
+
+fn get_score(commands: &Vec<Command>) -> usize {
+    lagoon(commands).len()
+}
+
+// Every cube dug out for the trench, with the color code of the command that dug it
+fn trench(commands: &Vec<Command>) -> HashMap<Coord, &str> {
+    let mut trench = HashMap::new();
+
+    let mut curr = Coord::default();
+    for comm in commands {
+        for _ in 0..comm.1 {
+            curr = curr.step(comm.0, 1);
+            _ = trench.insert(curr, comm.2.as_str());
+        }
+    }
+
+    trench
+}
+
+// Cubes outside the trench, next to each other, in a box one cube wider all round than the trench
+struct Outside {
+    trench: HashSet<Coord>,
+    min: Coord,
+    max: Coord,
+}
+
+impl Graph for Outside {
+    type Node = Coord;
+
+    fn neighbours(&self, c: &Coord) -> Vec<(Coord, usize)> {
+        c.neighbours4()
+            .filter(|next| (self.min.row..=self.max.row).contains(&next.row) && (self.min.col..=self.max.col).contains(&next.col))
+            .filter(|next| !self.trench.contains(next))
+            .map(|next| (next, 1))
+            .collect()
+    }
+}
+
+// The trench and the interior: every cube of the box that can't be reached from its corner
+fn lagoon(commands: &Vec<Command>) -> HashSet<Coord> {
+    let mut trench: HashSet<Coord> = trench(commands).into_keys().collect();
+    _ = trench.insert(Coord::default());
+
+    let min = Coord::new(trench.iter().map(|c| c.row).min().unwrap_or(0) - 1, trench.iter().map(|c| c.col).min().unwrap_or(0) - 1);
+    let max = Coord::new(trench.iter().map(|c| c.row).max().unwrap_or(0) + 1, trench.iter().map(|c| c.col).max().unwrap_or(0) + 1);
+    let outside = bfs(&Outside { trench, min, max }, min);
+    trace!(trace::MILESTONE, "outside", "min": [min.row, min.col], "max": [max.row, max.col], "cubes": outside.len());
+
+    (min.row..=max.row)
+        .flat_map(|row| (min.col..=max.col).map(move |col| Coord::new(row, col)))
+        .filter(|c| !outside.contains(c))
+        .collect()
+}
+
+/* --- Day 18: Lavaduct Lagoon ---
+Thanks to your efforts, the machine parts factory is one of the first factories up and running since the lavafall came back. However, to catch up with the large backlog of parts requests, the factory will also need a large supply of lava for a while; the Elves have already started creating a large lagoon nearby for this purpose.
+
+However, they aren't sure the lagoon will be big enough; they've asked you to take a look at the dig plan (your puzzle input). For example:
+
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+The digger starts in a 1 meter cube hole in the ground. They then dig the specified number of meters up (U), down (D), left (L), or right (R), clearing full 1 meter cubes as they go. The directions are given as seen from above, so if "up" were north, then "right" would be east, and so on. Each trench is also listed with the color that the edge of the trench should be painted as an RGB hexadecimal color code.
+
+When viewed from above, the above example dig plan would result in the following loop of trench (#) having been dug out from otherwise ground-level terrain (.):
+
+#######
+#.....#
+###...#
+..#...#
+..#...#
+###.###
+#...#..
+##..###
+.#....#
+.######
+At this point, the trench could contain 38 cubic meters of lava. However, this is just the edge of the lagoon; the next step is to dig out the interior so that it is one meter deep as well:
+
+#######
+#######
+#######
+..#####
+..#####
+#######
+#####..
+#######
+.######
+.######
+Now, the lagoon can contain a much more respectable 62 cubic meters of lava. While the interior is dug out, the edges are also painted according to the color codes in the dig plan.
+
+The Elves are concerned the lagoon won't be large enough; if they follow their dig plan, how many cubic meters of lava could it hold?
+
+--- Part Two ---
+The Elves were right to be concerned; the planned lagoon would be much too small.
+
+After a few minutes, someone realizes what happened; someone swapped the color and instruction parameters when producing the dig plan. They don't have time to fix the bug; one of them asks if you can extract the correct instructions from the hexadecimal codes.
+
+Each hexadecimal code is six hexadecimal digits long. The first five hexadecimal digits encode the distance in meters as a five-digit hexadecimal number. The last hexadecimal digit encodes the direction to dig: 0 means R, 1 means D, 2 means L, and 3 means U.
+
+So, in the above example, the hexadecimal codes can be converted into the true instructions:
+
+#70c710 = R 461937
+#0dc571 = D 56407
+#5713f0 = R 356671
+#d2c081 = D 863240
+#59c680 = R 367720
+#411b91 = D 266681
+#8ceee2 = L 577262
+#caa173 = U 829975
+#1b58a2 = L 112010
+#caa171 = D 829975
+#7807d2 = L 491645
+#a77fa3 = U 686074
+#015232 = L 5411
+#7a21e3 = U 500254
+Digging out this loop and its interior produces a lagoon that can hold an impressive 952408144115 cubic meters of lava.
+
+Convert the hexadecimal color codes into the correct instructions; if the Elves follow this new dig plan, how many cubic meters of lava could the lagoon hold? */
\ No newline at end of file
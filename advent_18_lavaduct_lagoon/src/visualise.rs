@@ -0,0 +1,30 @@
+use aoc_common::{render::{Color, Frame, Style, Visualise}, Coord, Grid};
+
+use crate::{lagoon, trench, Command, Day18};
+
+// The part 1 lagoon: the trench painted in its color codes around the dug out interior
+impl Visualise for Day18 {
+    fn frames(commands: &Vec<Command>) -> Vec<Frame> {
+        let (trench, lagoon) = (trench(commands), lagoon(commands));
+        let Some(top) = lagoon.iter().map(|c| c.row).min() else {
+            return Vec::new();
+        };
+        let left = lagoon.iter().map(|c| c.col).min().unwrap_or(0);
+        let bottom = lagoon.iter().map(|c| c.row).max().unwrap_or(0);
+        let right = lagoon.iter().map(|c| c.col).max().unwrap_or(0);
+
+        let (rows, cols) = ((bottom - top + 1) as usize, (right - left + 1) as usize);
+        let area = Grid::new(rows, cols, (0..rows * cols).map(|i| Coord::new(top + (i / cols) as i64, left + (i % cols) as i64)).collect());
+        vec![Frame::new(format!("{} cubes of lava", lagoon.len()), &area, |_, coord| match trench.get(coord) {
+            Some(code) => Style::new('#', color(code)),
+            None if lagoon.contains(coord) => Style::new('#', Color::BLUE),
+            None => Style::new('.', Color::GREY),
+        })]
+    }
+}
+
+// `#70c710` is red 0x70, green 0xc7 and blue 0x10
+fn color(code: &str) -> Color {
+    let channel = |i: usize| u8::from_str_radix(&code[i..i + 2], 16).unwrap_or(0);
+    Color(channel(1), channel(3), channel(5))
+}
@@ -0,0 +1,18 @@
+use advent_18_lavaduct_lagoon::{generate::generate, Day18};
+use aoc_common::testing::{differential, part1, part2};
+
+// Color codes that repeat the plain steps, so part 2 digs the same lagoon as part 1
+fn colors_from_steps(input: &str) -> String {
+    input.lines().map(|line| {
+        let mut tokens = line.split_whitespace();
+        let (dir, steps) = (tokens.next().unwrap(), tokens.next().unwrap().parse::<u32>().unwrap());
+        let code = ["R", "D", "L", "U"].iter().position(|d| *d == dir).unwrap();
+
+        format!("{dir} {steps} (#{steps:05x}{code})\n")
+    }).collect()
+}
+
+#[test]
+fn shoelace_matches_flood_fill() {
+    differential(0..100, |seed| colors_from_steps(&generate(seed, 1 + seed as usize % 8)), part2::<Day18>, part1::<Day18>);
+}
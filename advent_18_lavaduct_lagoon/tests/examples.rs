@@ -0,0 +1,14 @@
+use advent_18_lavaduct_lagoon::Day18;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day18>(EXAMPLE), "62");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day18>(EXAMPLE), "952408144115");
+}
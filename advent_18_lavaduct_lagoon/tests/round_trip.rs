@@ -0,0 +1,7 @@
+use advent_18_lavaduct_lagoon::{generate::generate, Day18};
+use aoc_common::testing::round_trip;
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day18>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
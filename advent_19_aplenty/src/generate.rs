@@ -0,0 +1,50 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::Rng;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+// Up to `size` workflows branching out of `in` without ever sending a part back, then `size` parts
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let mut names = HashSet::from(["in".to_string(), "A".to_string(), "R".to_string()]);
+    let mut pending = VecDeque::from(["in".to_string()]);
+    let mut count = 1;
+    let mut workflows = Vec::new();
+    while let Some(name) = pending.pop_front() {
+        let mut destination = |rng: &mut Rng| {
+            if count < size && rng.chance(0.6) {
+                let next = loop {
+                    let next = (0..rng.range(2..=3)).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>();
+                    if names.insert(next.clone()) {
+                        break next;
+                    }
+                };
+                count += 1;
+                pending.push_back(next.clone());
+                next
+            } else {
+                rng.choose(&["A", "R"]).to_string()
+            }
+        };
+
+        let mut rules = (0..rng.range(1..=4)).map(|_| {
+            let category = rng.choose(&CATEGORIES);
+            let sign = rng.choose(&['<', '>']);
+            let threshold = rng.range(1..=4000);
+            format!("{category}{sign}{threshold}:{}", destination(&mut rng))
+        }).collect::<Vec<_>>();
+        rules.push(destination(&mut rng));
+
+        workflows.push(format!("{name}{{{}}}", rules.join(",")));
+    }
+
+    rng.shuffle(&mut workflows);
+    let parts = (0..size).map(|_| {
+        let ratings = CATEGORIES.map(|category| format!("{category}={}", rng.range(1..=4000)));
+        format!("{{{}}}", ratings.join(","))
+    }).collect::<Vec<_>>();
+
+    format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+}
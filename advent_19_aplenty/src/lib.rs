@@ -0,0 +1,379 @@
+pub mod generate;
+
+use std::{collections::{HashMap, VecDeque}, fmt};
+use aoc_common::{trace, Print, Result, Solution, Source};
+
+#[derive(Clone, PartialEq)]
+pub struct Command(pub String, pub Option<Condition>);
+
+#[derive(Clone, PartialEq)]
+pub struct Condition(pub Feature, pub Sign, pub i64);
+
+// The four categories a part is rated in, in the order of its ratings
+#[derive(Clone, Copy, PartialEq)]
+pub enum Feature {
+    X,
+    M,
+    A,
+    S
+}
+
+impl Feature {
+    fn new(c: &str) -> Option<Feature> {
+        match c {
+            "x" => Some(Feature::X),
+            "m" => Some(Feature::M),
+            "a" => Some(Feature::A),
+            "s" => Some(Feature::S),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let feature = match self {
+            Feature::X => 'x',
+            Feature::M => 'm',
+            Feature::A => 'a',
+            Feature::S => 's',
+        };
+        write!(f, "{feature}")
+    }
+}
+
+#[derive(PartialEq)]
+pub struct Part(pub i64, pub i64, pub i64, pub i64);
+
+impl Part {
+    fn sum(&self) -> i64 {
+        self.0 + self.1 + self.2 + self.3
+    }
+
+    fn rating(&self, feature: Feature) -> i64 {
+        match feature {
+            Feature::X => self.0,
+            Feature::M => self.1,
+            Feature::A => self.2,
+            Feature::S => self.3,
+        }
+    }
+}
+
+#[derive(PartialEq)]
+pub struct Workflow {
+    pub commands: HashMap<String, Vec<Command>>,
+    pub parts: Vec<Part>
+}
+
+impl Workflow {
+    fn get_combinations(&self, coms: Vec<Command>, mut ranges: Vec<(usize, usize)>) -> usize {
+        let mut possibilities = 0usize;
+    
+        let mut queue = VecDeque::new();
+        queue.extend(coms);
+        
+        while let Some(c) = queue.pop_front() {
+            let mut deeper = ranges.clone();
+            match c.1 {
+                Some(Condition(feature, Sign::More, threshold)) => {
+                    let idx = feature as usize;
+                    let threshold = threshold as usize;
+
+                    if deeper[idx].1 > threshold {
+                        deeper[idx] = (deeper[idx].0.max(threshold + 1), deeper[idx].1);
+                        possibilities += match c.0.as_str() {
+                            "A" => self.get_range_poss(&deeper),
+                            "R" => 0,
+                            to => self.get_combinations(self.commands[to].clone(), deeper)
+                        }
+                    }
+
+                    if ranges[idx].0 < threshold {
+                        ranges[idx] = (ranges[idx].0, threshold);
+                    } else {
+                        break;
+                    }
+                },
+                Some(Condition(feature, Sign::Less, threshold)) => {
+                    let idx = feature as usize;
+                    let threshold = threshold as usize;
+                    
+                    if deeper[idx].0 < threshold {
+                        deeper[idx] = (deeper[idx].0, deeper[idx].1.min(threshold - 1));
+                        possibilities += match c.0.as_str() {
+                            "A" => self.get_range_poss(&deeper),
+                            "R" => 0,
+                            to => self.get_combinations(self.commands[to].clone(), deeper)
+                        }
+                    } 
+
+                    if ranges[idx].1 > threshold {
+                        ranges[idx] = (threshold, ranges[idx].1);
+                    } else {
+                        break;
+                    }
+                },
+                None => {
+                    possibilities += match c.0.as_str() {
+                        "A" => self.get_range_poss(&ranges),
+                        "R" => 0,
+                        to => self.get_combinations(self.commands[to].clone(), ranges.clone())
+                    }
+                }
+            }
+        }
+
+        possibilities
+    }
+
+    fn get_range_poss(&self, ranges: &[(usize, usize)]) -> usize {
+        ranges.iter().map(|(start, end)| (*start..*end).len() + 1).product()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum Sign {
+    More,
+    Less
+}
+
+impl Sign {
+    fn new(c: char)  -> Option<Sign> {
+        match c {
+            '>' => Some(Sign::More),
+            '<' => Some(Sign::Less),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.1 {
+            Some(Condition(feature, sign, threshold)) => {
+                let sign = match sign {
+                    Sign::More => '>',
+                    Sign::Less => '<',
+                };
+                write!(f, "{feature}{sign}{threshold}:{}", self.0)
+            },
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.0, self.1, self.2, self.3)
+    }
+}
+
+// The workflows in name order, then the parts
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.commands.keys().collect::<Vec<_>>();
+        names.sort();
+        for name in names {
+            let commands = self.commands[name].iter().map(Command::to_string).collect::<Vec<_>>();
+            writeln!(f, "{name}{{{}}}", commands.join(","))?;
+        }
+
+        for part in &self.parts {
+            write!(f, "\n{part}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Every part starts at this workflow
+const START: &str = "in";
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Workflow;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Workflow> {
+        const EXPECTED_FEATURE: &str = "`x`, `m`, `a` or `s`";
+
+        let source = Source::new(input);
+        let mut commands = HashMap::new();
+        let mut targets = Vec::new();
+        let (comms, part_feat) = source.split_once(input, "\n\n")?;
+        for com in comms.lines() {
+            let (name, rest) = source.split_once(com, "{")?;
+
+            let mut flow_commands = Vec::new();
+            for command in rest.trim_end_matches('}').split(',') {
+
+                if command.contains('>') || command.contains('<'){
+                    let (condition, to) = source.split_once(command, ":")?;
+
+                    let feature = condition.get(..1).and_then(Feature::new).ok_or_else(|| source.unexpected(condition, EXPECTED_FEATURE))?;
+                    let sign = condition.get(1..2).ok_or_else(|| source.missing(condition, "`<` or `>`"))?;
+                    let sign = sign.chars().next().and_then(Sign::new).ok_or_else(|| source.unexpected(sign, "`<` or `>`"))?;
+                    let threshold = source.number(&condition[2..])?;
+
+                    let command = Command(to.to_string(), Some(Condition(feature, sign, threshold)));
+                    flow_commands.push(command);
+                    targets.push(to);
+                } else {
+                    let variable = command.to_string();
+                    flow_commands.push(Command(variable, None));
+                    targets.push(command);
+                }
+            }
+
+            commands.insert(name.to_string(), flow_commands);
+        }
+
+        // Parts have to end up accepted or rejected, every rule sends them on to one that can do that
+        if let Some(to) = targets.into_iter().find(|to| !matches!(*to, "A" | "R") && !commands.contains_key(*to)) {
+            return Err(source.unexpected(to, "`A`, `R` or a workflow").into());
+        }
+        if !commands.contains_key(START) {
+            return Err(format!("There is no workflow `{START}` to start from").into());
+        }
+
+        let mut parts = Vec::new();
+        for part_features in part_feat.lines() {
+            let mut x = 0;
+            let mut m = 0;
+            let mut a = 0;
+            let mut s = 0;
+
+            for feature in part_features.trim_matches(|c| c == '{' || c == '}').split(',') {
+                let (feature, ranking) = source.split_once(feature, "=")?;
+                let ranking = source.number(ranking.trim())?;
+
+                match Feature::new(feature.trim()) {
+                    Some(Feature::X) => x = ranking,
+                    Some(Feature::M) => m = ranking,
+                    Some(Feature::A) => a = ranking,
+                    Some(Feature::S) => s = ranking,
+                    None => return Err(source.unexpected(feature.trim(), EXPECTED_FEATURE).into())
+                }
+            }
+
+            parts.push(Part(x, m, a, s))
+        }
+
+        Ok(Workflow { commands, parts})
+    }
+
+    fn part1(workflow: &Workflow) -> Result<i64> {
+        let mut result = 0i64;
+
+        for part in &workflow.parts {
+            let mut queue = VecDeque::new();
+            queue.push_back(START);
+
+            while let Some(name) = queue.pop_front() {
+                for command in &workflow.commands[name] {
+                    let applies = match &command.1 {
+                        Some(Condition(mark, Sign::More, threshold)) => part.rating(*mark) > *threshold,
+                        Some(Condition(mark, Sign::Less, threshold)) => part.rating(*mark) < *threshold,
+                        None => true
+                    };
+                    if !applies {
+                        continue;
+                    }
+
+                    match command.0.as_str() {
+                        "A" => {
+                            trace!(trace::MILESTONE, "accept", "from": name, "ratings": [part.0, part.1, part.2, part.3]);
+                            result += part.sum()
+                        },
+                        "R" => trace!(trace::MILESTONE, "reject", "from": name, "ratings": [part.0, part.1, part.2, part.3]),
+                        to => {
+                            trace!(trace::STEP, "hop", "from": name, "to": to, "ratings": [part.0, part.1, part.2, part.3]);
+                            queue.push_back(to)
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn part2(workflow: &Workflow) -> Result<usize> {
+        let result = workflow.get_combinations(workflow.commands[START].clone(), vec![(1, 4000), (1, 4000), (1, 4000), (1, 4000)]);
+        Ok(result)
+    }
+}
+
+impl Print for Day19 {
+    fn print(workflow: &Workflow) -> String {
+        workflow.to_string()
+    }
+}
+
+/*
+
+--- Day 19: Aplenty ---
+The Elves of Gear Island are thankful for your help and send you on your way. They even have a hang glider that someone stole from Desert Island; since you're already going that direction, it would help them a lot if you would use it to get down there and return it to them.
+
+As you reach the bottom of the relentless avalanche of machine parts, you discover that they're already forming a formidable heap. Don't worry, though - a group of Elves is already here organizing the parts, and they have a system.
+
+To start, each part is rated in each of four categories:
+
+x: Extremely cool looking
+m: Musical (it makes a noise when you hit it)
+a: Aerodynamic
+s: Shiny
+Then, each part is sent through a series of workflows that will ultimately accept or reject the part. Each workflow has a name and contains a list of rules; each rule specifies a condition and where to send the part if the condition is true. The first rule that matches the part being considered is applied immediately, and the part moves on to the destination described by the rule. (The last rule in each workflow has no condition and always applies if reached.)
+
+Consider the workflow ex{x>10:one,m<20:two,a>30:R,A}. This workflow is named ex and contains four rules. If workflow ex were considering a specific part, it would perform the following steps in order:
+
+Rule "x>10:one": If the part's x is more than 10, send the part to the workflow named one.
+Rule "m<20:two": Otherwise, if the part's m is less than 20, send the part to the workflow named two.
+Rule "a>30:R": Otherwise, if the part's a is more than 30, the part is immediately rejected (R).
+Rule "A": Otherwise, because no other rules matched the part, the part is immediately accepted (A).
+If a part is sent to another workflow, it immediately switches to the start of that workflow instead and never returns. If a part is accepted (sent to A) or rejected (sent to R), the part immediately stops any further processing.
+
+The system works, but it's not keeping up with the torrent of weird metal shapes. The Elves ask if you can help sort a few parts and give you the list of workflows and some part ratings (your puzzle input). For example:
+
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+The workflows are listed first, followed by a blank line, then the ratings of the parts the Elves would like you to sort. All parts begin in the workflow named in. In this example, the five listed parts go through the following workflows:
+
+{x=787,m=2655,a=1222,s=2876}: in -> qqz -> qs -> lnx -> A
+{x=1679,m=44,a=2067,s=496}: in -> px -> rfg -> gd -> R
+{x=2036,m=264,a=79,s=2244}: in -> qqz -> hdj -> pv -> A
+{x=2461,m=1339,a=466,s=291}: in -> px -> qkq -> crn -> R
+{x=2127,m=1623,a=2188,s=1013}: in -> px -> rfg -> A
+Ultimately, three parts are accepted. Adding up the x, m, a, and s rating for each of the accepted parts gives 7540 for the part with x=787, 4623 for the part with x=2036, and 6951 for the part with x=2127. Adding all of the ratings for all of the accepted parts gives the sum total of 19114.
+
+Sort through all of the parts you've been given; what do you get if you add together all of the rating numbers for all of the parts that ultimately get accepted?
+
+--- Part Two ---
+Even with your help, the sorting process still isn't fast enough.
+
+One of the Elves comes up with a new plan: rather than sort parts individually through all of these workflows, maybe you can figure out in advance which combinations of ratings will be accepted or rejected.
+
+Each of the four ratings (x, m, a, s) can have an integer value ranging from a minimum of 1 to a maximum of 4000. Of all possible distinct combinations of ratings, your job is to figure out which ones will be accepted.
+
+In the above example, there are 167409079868000 distinct combinations of ratings that will be accepted.
+
+Consider only your list of workflows; the list of part ratings that the Elves wanted you to sort is no longer relevant. How many distinct combinations of ratings will be accepted by the Elves' workflows?*/
\ No newline at end of file
@@ -0,0 +1,21 @@
+use advent_19_aplenty::Day19;
+use aoc_common::{testing::{part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day19>(EXAMPLE), "19114");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day19>(EXAMPLE), "167409079868000");
+}
+
+#[test]
+fn undefined_workflows_are_an_error() {
+    let error = Day19::parse("in{x>10:foo,R}\n\n{x=1,m=2,a=3,s=4}").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 1, column 9: expected `A`, `R` or a workflow, found `foo`");
+}
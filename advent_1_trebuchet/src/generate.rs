@@ -0,0 +1,27 @@
+use aoc_common::Rng;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+// `size` calibration lines: letters with spelled out digits mixed in and at least one real digit
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size).map(|_| {
+        let mut pieces = Vec::new();
+        for _ in 0..rng.range(1..=8) {
+            match rng.below(3) {
+                0 => pieces.push(rng.range(1..=9).to_string()),
+                1 => pieces.push(rng.choose(&WORDS).to_string()),
+                _ => pieces.push(letters(&mut rng)),
+            }
+        }
+
+        let digit = rng.range(1..=9).to_string();
+        pieces.insert(rng.below(pieces.len() + 1), digit);
+        pieces.concat() + "\n"
+    }).collect()
+}
+
+fn letters(rng: &mut Rng) -> String {
+    (0..rng.range(1..=4)).map(|_| (b'a' + rng.below(26) as u8) as char).collect()
+}
@@ -0,0 +1,23 @@
+use advent_1_trebuchet::Day1;
+use aoc_common::{testing::{parse, part1, part2}, Solution};
+
+const EXAMPLE_1: &str = include_str!("../fixtures/example_1.txt");
+const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day1>(EXAMPLE_1), "142");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day1>(EXAMPLE_2), "281");
+}
+
+#[test]
+fn lines_without_a_digit_are_an_error() {
+    let lines = parse::<Day1>("1abc2\nab٣c\n");
+
+    assert_eq!(Day1::part1(&lines).unwrap_err().to_string(), "Number not found in `ab٣c`");
+    assert_eq!(Day1::part2(&lines).unwrap_err().to_string(), "Number not found in `ab٣c`");
+}
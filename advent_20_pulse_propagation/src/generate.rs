@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+// `size` twelve bit counters behind the broadcaster, like the real input
+pub fn generate(seed: u64, size: usize) -> String {
+    counters(seed, size, 12)
+}
+
+// `size` counters of `bits` bits behind the broadcaster. Every counter is a chain of flip-flops wired
+// to a conjunction that resets it once it reaches its period, a prime with the top bit set, and then
+// sends a high pulse through an inverter into the conjunction in front of `rx`. There have to be
+// `size` such primes, small counters keep the answer small enough to press the button that often
+pub fn counters(seed: u64, size: usize, bits: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut name = namer();
+
+    let gate = name(&mut rng);
+    let mut modules = vec![format!("&{gate} -> rx")];
+    let mut starts = Vec::new();
+    let mut periods = HashSet::new();
+    for _ in 0..size.max(1) {
+        let period = loop {
+            let period = rng.range(1 << (bits - 1)..1 << bits);
+            if is_prime(period) && periods.insert(period) {
+                break period;
+            }
+        };
+
+        let bits = (0..bits).map(|_| name(&mut rng)).collect::<Vec<_>>();
+        let (hub, inverter) = (name(&mut rng), name(&mut rng));
+
+        // Bits set in the period feed the hub, the hub resets the others and bumps the lowest bit
+        let mut resets = vec![bits[0].clone()];
+        for (i, bit) in bits.iter().enumerate() {
+            let mut outputs = bits.get(i + 1).cloned().into_iter().collect::<Vec<_>>();
+            if period >> i & 1 == 1 {
+                outputs.push(hub.clone());
+            } else {
+                resets.push(bit.clone());
+            }
+
+            rng.shuffle(&mut outputs);
+            modules.push(format!("%{bit} -> {}", outputs.join(", ")));
+        }
+
+        resets.push(inverter.clone());
+        rng.shuffle(&mut resets);
+        modules.push(format!("&{hub} -> {}", resets.join(", ")));
+        modules.push(format!("&{inverter} -> {gate}"));
+        starts.push(bits[0].clone());
+    }
+
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut modules);
+    modules.join("\n") + "\n"
+}
+
+// Small counters like `counters` with a few wires added at random, from a flip-flop or a conjunction into
+// an inverter or into the conjunction in front of `rx`, and part 2's assumptions only hold for some of them
+pub fn tangled(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut modules = counters(rng.next_u64(), size.clamp(1, 3), 5).lines().map(str::to_string).collect::<Vec<_>>();
+
+    let gate = modules.iter().find_map(|m| m.strip_suffix(" -> rx")).expect("counters have a gate")[1..].to_string();
+    let inverters = modules.iter().filter_map(|m| m.strip_suffix(&format!(" -> {gate}"))).map(|m| m[1..].to_string());
+    let targets = inverters.chain([gate.clone()]).collect::<Vec<_>>();
+
+    // Inverters only lead to the gate and the gate only to `rx`, so no loop keeps a press going forever
+    let sources = (0..modules.len())
+        .filter(|&i| modules[i].starts_with(['%', '&']) && !targets.iter().any(|target| modules[i][1..].starts_with(&format!("{target} -> "))))
+        .collect::<Vec<_>>();
+    for _ in 0..rng.below(3) {
+        let from = *rng.choose(&sources);
+        modules[from] += &format!(", {}", rng.choose(&targets));
+    }
+
+    modules.join("\n") + "\n"
+}
+
+// Two letter names, never the same twice and never `rx`
+fn namer() -> impl FnMut(&mut Rng) -> String {
+    let mut names = HashSet::from(["rx".to_string()]);
+    move |rng| loop {
+        let name = (0..2).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>();
+        if names.insert(name.clone()) {
+            break name;
+        }
+    }
+}
+
+fn is_prime(n: u64) -> bool {
+    n > 1 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
@@ -0,0 +1,468 @@
+pub mod generate;
+pub mod visualise;
+
+use std::{collections::{HashMap, HashSet, VecDeque}, fmt, ops::Range};
+use aoc_common::{params, trace, BigUint, Configurable, ParseResult, Print, Result, Solution, Source};
+
+#[derive(Clone, Default, PartialEq)]
+pub struct Schema {
+    pub modules: HashMap<String, Module>
+}
+
+impl Schema {
+    fn get_cycle(&mut self) -> [u64; 2] {
+        let mut pcount = [0, 0];
+        for sent in self.press() {
+            pcount[sent.pulse as usize] += 1;
+        }
+
+        pcount
+    }
+
+    // The presses until `input` first turns `gate` to remembering a high pulse, and the waves of such a press
+    // from that pulse until the low one that turns it back. The modules feeding `input` are pressed until
+    // they are back in a state they were in before, from then on they repeat, so that covers all `input`
+    // will ever send, and it has to do exactly the same every that many presses and nothing in between
+    fn get_cycles_to_target(&mut self, input: &str, gate: &str) -> Result<(u64, Range<usize>)> {
+        let feeding = self.feeding(input);
+        let mut seen = HashMap::from([(self.state(&feeding), 0)]);
+        let mut first: Option<(u64, Range<usize>)> = None;
+        let mut presses = 0;
+        loop {
+            presses += 1;
+
+            let (mut high, mut window) = (None, None);
+            for sent in self.press().into_iter().filter(|sent| sent.from == input && sent.to == gate) {
+                match (sent.pulse, high) {
+                    (Pulse::High, None) => high = Some(sent.wave),
+                    (Pulse::Low, Some(wave)) if window.replace(wave..sent.wave).is_none() => high = None,
+                    (Pulse::Low, Some(_)) => return Err(format!("`{input}` turns `{gate}` high more than once in press {presses}").into()),
+                    _ => (),
+                }
+            }
+            if high.is_some() {
+                return Err(format!("`{input}` leaves `{gate}` remembering a high pulse after press {presses}").into());
+            }
+
+            match (&first, window) {
+                (None, Some(window)) => first = Some((presses, window)),
+                (Some((cycles, expected)), window) if window.as_ref() != presses.is_multiple_of(*cycles).then_some(expected) => {
+                    return Err(format!("`{input}` doesn't turn `{gate}` high every {cycles} presses and only then").into());
+                },
+                _ => (),
+            }
+
+            let Some(before) = seen.insert(self.state(&feeding), presses) else {
+                continue;
+            };
+            return match first {
+                Some((cycles, window)) if (presses - before).is_multiple_of(cycles) => {
+                    trace!(trace::MILESTONE, "cycle", "from": input, "to": gate, "presses": cycles);
+                    Ok((cycles, window))
+                },
+                Some((cycles, _)) => Err(format!("`{input}` repeats every {} presses, not every {cycles}", presses - before).into()),
+                None => Err(format!("`{input}` never sends a high pulse to `{gate}`").into()),
+            };
+        }
+    }
+
+    // `module` and every module whose pulses can reach it, nothing else changes what it sends
+    fn feeding(&self, module: &str) -> Vec<String> {
+        let mut feeding = HashSet::from([module.to_string()]);
+        let mut queue = vec![module.to_string()];
+        while let Some(name) = queue.pop() {
+            for sender in self.modules.values().filter(|sender| sender.connects.contains(&name)) {
+                if feeding.insert(sender.name.clone()) {
+                    queue.push(sender.name.clone());
+                }
+            }
+        }
+
+        let mut feeding = feeding.into_iter().collect::<Vec<_>>();
+        feeding.sort();
+        feeding
+    }
+
+    // Every flip-flop and every pulse a conjunction remembers among `modules`
+    fn state(&self, modules: &[String]) -> Vec<bool> {
+        let mut state = Vec::new();
+        for module in modules.iter().filter_map(|name| self.modules.get(name)) {
+            match &module.mod_type {
+                Type::FlipFlop(on) => state.push(*on),
+                Type::Conjuction(connections) => {
+                    let mut inputs = connections.iter().collect::<Vec<_>>();
+                    inputs.sort_by_key(|(name, _)| *name);
+                    state.extend(inputs.into_iter().map(|(_, pulse)| *pulse == Pulse::High));
+                },
+                Type::Broadcaster => (),
+            }
+        }
+
+        state
+    }
+
+    // Pushes the button once, every pulse sent in the order they arrive
+    fn press(&mut self) -> Vec<Sent> {
+        let mut pulses = Vec::new();
+
+        let mut queue = VecDeque::new();
+        queue.push_back(("button".to_string(), "broadcaster".to_string(), Pulse::Low, 0));
+    
+        while let Some((sender, name, pulse, wave)) = queue.pop_front() {
+            trace!(trace::DETAIL, "pulse", "from": sender, "to": name, "pulse": pulse.name());
+    
+            if let Some(Module { name, mod_type, connects }) = self.modules.get_mut(&name) {
+                match mod_type {
+                    Type::Broadcaster => {
+                        for to in connects {
+                            queue.push_back((name.to_string(), to.to_string(), pulse, wave + 1))
+                        }
+                    },
+                    Type::FlipFlop(state) if pulse == Pulse::Low => {
+                        *state = !*state;
+                        
+                        let pulse = Pulse::to_pulse(*state);
+                        for to in  connects {
+                            queue.push_back((name.to_string(), to.to_string(), pulse, wave + 1))
+                        }
+                    },
+                    Type::Conjuction(connections) => {
+                        connections.insert(sender.to_string(), pulse);
+
+                        let is_high = !connections.iter().all(|x| *x.1 == Pulse::High);
+                        let pulse = Pulse::to_pulse(is_high);
+                        for to in connects {
+                            queue.push_back((name.to_string(), to.to_string(), pulse, wave + 1))
+                        }
+                    },
+                    _ => ()
+                }
+            }
+            pulses.push(Sent { from: sender, to: name, pulse, wave });
+        }
+
+        pulses
+    }
+}
+
+// A pulse as it arrived. The queue handles one wave after the other, `wave` counts the modules the
+// pulse went through since the button
+pub struct Sent {
+    pub from: String,
+    pub to: String,
+    pub pulse: Pulse,
+    pub wave: usize,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Module {
+    pub name: String,
+    pub mod_type: Type,
+    pub connects: Vec<String>,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum Type {
+    FlipFlop(bool),
+    Conjuction(HashMap<String, Pulse>),
+    Broadcaster
+}
+
+impl Type {
+    const FLIPFLOP: char = '%';
+    const CONJUCTION: char = '&';
+}
+
+// Only the wiring, so a schema already pressed prints as it was before the first press
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mod_type {
+            Type::FlipFlop(_) => write!(f, "{}", Type::FLIPFLOP)?,
+            Type::Conjuction(_) => write!(f, "{}", Type::CONJUCTION)?,
+            Type::Broadcaster => (),
+        }
+        write!(f, "{} -> {}", self.name, self.connects.join(", "))
+    }
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut modules = self.modules.values().collect::<Vec<_>>();
+        modules.sort_by_key(|module| &module.name);
+        let modules = modules.iter().map(|module| module.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", modules.join("\n"))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum Pulse {
+    Low, 
+    High
+}
+
+impl Pulse {
+    fn to_pulse(state: bool) -> Pulse {
+        match state {
+            false => Pulse::Low,
+            true => Pulse::High
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Pulse::Low => "low",
+            Pulse::High => "high"
+        }
+    }
+}
+
+params! {
+    // Times the button is pushed in part 1
+    pub struct Day20Params {
+        presses: u64 = 1000,
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Schema;
+    type Answer1 = u128;
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<Schema> {
+        let source = Source::new(input);
+        let mut conjunctions = HashMap::<String, HashMap<String, Pulse>>::new();
+
+        let mut modules = input.lines().map(|line| {
+            let (module, connections) = source.split_once(line, "->")?;
+            let module = module.trim();
+            let connects = connections.split(',')
+                .map(|c| c.trim().to_string())
+                .collect::<Vec<_>>();
+
+            let Some(prefix) = module.chars().next() else {
+                return Err(source.unexpected(module, "a module name"));
+            };
+
+            let (mod_type, name) = match prefix {
+                Type::FLIPFLOP => (Type::FlipFlop(false), module.trim_start_matches(Type::FLIPFLOP)),
+                Type::CONJUCTION => {
+                    let name = module.trim_start_matches(Type::CONJUCTION);
+                    conjunctions.insert(name.to_string(), HashMap::new());
+                    (Type::Conjuction(HashMap::new()), name)
+                },
+                _ => (Type::Broadcaster, module)
+            };
+
+            Ok((name.to_string(), Module { name: name.to_string(), mod_type, connects}))
+        }).collect::<ParseResult<HashMap<String, Module>>>()?;
+
+        for (name, module) in &modules {
+            for connect in &module.connects {
+                if let Some(conj) = conjunctions.get_mut(connect) {
+                    conj.insert(name.to_string(), Pulse::Low);
+                }
+            }
+        }
+
+        for (name, module) in modules.iter_mut() {
+            if let Some(conj) = conjunctions.get(name) {
+                module.mod_type = Type::Conjuction(conj.clone());
+            }
+        }
+
+        Ok(Schema { modules })
+    }
+
+    fn part1(schema: &Schema) -> Result<u128> {
+        Self::part1_with(schema, &Day20Params::default())
+    }
+
+    fn part2(schema: &Schema) -> Result<BigUint> {
+        Self::part2_with(schema, &Day20Params::default())
+    }
+}
+
+impl Configurable for Day20 {
+    type Params = Day20Params;
+
+    fn part1_with(schema: &Schema, params: &Day20Params) -> Result<u128> {
+        Ok(pulse_product(schema, params.presses))
+    }
+
+    fn part2_with(schema: &Schema, _: &Day20Params) -> Result<BigUint> {
+        // `rx` sits behind a single conjunction, which sends the low pulse once all of its inputs were high
+        let senders = schema.modules.values().filter(|m| m.connects.iter().any(|c| c == "rx")).collect::<Vec<_>>();
+        let gate = match senders[..] {
+            [gate] => gate,
+            [] => return Err("No module sends pulses to `rx`".into()),
+            _ => return Err("More than one module sends pulses to `rx`".into()),
+        };
+        let Type::Conjuction(nodes_to_rx) = &gate.mod_type else {
+            return Err(format!("`{}` in front of `rx` is not a conjunction", gate.name).into());
+        };
+
+        // The inputs only keep their own beat if nothing but the broadcaster feeds more than one of them
+        let mut fed = HashMap::new();
+        for input in nodes_to_rx.keys() {
+            for module in schema.feeding(input).into_iter().filter(|module| module != "broadcaster") {
+                if module == gate.name {
+                    return Err(format!("`{}` feeds its own input `{input}`", gate.name).into());
+                }
+                if let Some(other) = fed.insert(module.clone(), input) {
+                    return Err(format!("`{module}` feeds both `{other}` and `{input}`").into());
+                }
+            }
+        }
+
+        let mut multipliers = Vec::<u64>::new();
+        let mut windows = Vec::new();
+        for target in nodes_to_rx.keys() {
+            let mut clean_schema = schema.clone();
+            let (multiplier, window) = clean_schema.get_cycles_to_target(target, &gate.name)?;
+            multipliers.push(multiplier);
+            windows.push(window);
+        } 
+
+        // All of them are high at once in the press they all turn high in, unless one is already back low
+        // before another one turns high
+        let (last_high, first_low) = (windows.iter().map(|w| w.start).max(), windows.iter().map(|w| w.end).min());
+        if last_high >= first_low {
+            return Err(format!("The inputs of `{}` are never all high at once", gate.name).into());
+        }
+
+        // Every counter adds a factor, a few dozen of them outgrow any machine integer
+        let result = BigUint::lcm_of(&multipliers);
+        Ok(result)
+    }
+}
+
+impl Print for Day20 {
+    fn print(schema: &Schema) -> String {
+        schema.to_string()
+    }
+}
+
+pub fn pulse_product(schema: &Schema, cycles: u64) -> u128 {
+    let mut schema = schema.clone();
+    let mut pulses = [0, 0];
+    for press in 1..=cycles {
+        let cyc_puls = schema.get_cycle();
+        trace!(trace::STEP, "press", "press": press, "low": cyc_puls[0], "high": cyc_puls[1]);
+        pulses[0] += cyc_puls[0];
+        pulses[1] += cyc_puls[1];
+    }
+
+    pulses[0] as u128 * pulses[1] as u128
+}
+
+/*--- Day 20: Pulse Propagation ---
+With your help, the Elves manage to find the right parts and fix all of the machines. Now, they just need to send the command to boot up the machines and get the sand flowing again.
+
+The machines are far apart and wired together with long cables. The cables don't connect to the machines directly, but rather to communication modules attached to the machines that perform various initialization tasks and also act as communication relays.
+
+Modules communicate using pulses. Each pulse is either a high pulse or a low pulse. When a module sends a pulse, it sends that type of pulse to each module in its list of destination modules.
+
+There are several different types of modules:
+
+Flip-flop modules (prefix %) are either on or off; they are initially off. If a flip-flop module receives a high pulse, it is ignored and nothing happens. However, if a flip-flop module receives a low pulse, it flips between on and off. If it was off, it turns on and sends a high pulse. If it was on, it turns off and sends a low pulse.
+
+Conjunction modules (prefix &) remember the type of the most recent pulse received from each of their connected input modules; they initially default to remembering a low pulse for each input. When a pulse is received, the conjunction module first updates its memory for that input. Then, if it remembers high pulses for all inputs, it sends a low pulse; otherwise, it sends a high pulse.
+
+There is a single broadcast module (named broadcaster). When it receives a pulse, it sends the same pulse to all of its destination modules.
+
+Here at Desert Machine Headquarters, there is a module with a single button on it called, aptly, the button module. When you push the button, a single low pulse is sent directly to the broadcaster module.
+
+After pushing the button, you must wait until all pulses have been delivered and fully handled before pushing it again. Never push the button if modules are still processing pulses.
+
+Pulses are always processed in the order they are sent. So, if a pulse is sent to modules a, b, and c, and then module a processes its pulse and sends more pulses, the pulses sent to modules b and c would have to be handled first.
+
+The module configuration (your puzzle input) lists each module. The name of the module is preceded by a symbol identifying its type, if any. The name is then followed by an arrow and a list of its destination modules. For example:
+
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+In this module configuration, the broadcaster has three destination modules named a, b, and c. Each of these modules is a flip-flop module (as indicated by the % prefix). a outputs to b which outputs to c which outputs to another module named inv. inv is a conjunction module (as indicated by the & prefix) which, because it has only one input, acts like an inverter (it sends the opposite of the pulse type it receives); it outputs to a.
+
+By pushing the button once, the following pulses are sent:
+
+button -low-> broadcaster
+broadcaster -low-> a
+broadcaster -low-> b
+broadcaster -low-> c
+a -high-> b
+b -high-> c
+c -high-> inv
+inv -low-> a
+a -low-> b
+b -low-> c
+c -low-> inv
+inv -high-> a
+After this sequence, the flip-flop modules all end up off, so pushing the button again repeats the same sequence.
+
+Here's a more interesting example:
+
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+This module configuration includes the broadcaster, two flip-flops (named a and b), a single-input conjunction module (inv), a multi-input conjunction module (con), and an untyped module named output (for testing purposes). The multi-input conjunction module con watches the two flip-flop modules and, if they're both on, sends a low pulse to the output module.
+
+Here's what happens if you push the button once:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -high-> inv
+a -high-> con
+inv -low-> b
+con -high-> output
+b -high-> con
+con -low-> output
+Both flip-flops turn on and a low pulse is sent to output! However, now that both flip-flops are on and con remembers a high pulse from each of its two inputs, pushing the button a second time does something different:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -low-> inv
+a -low-> con
+inv -high-> b
+con -high-> output
+Flip-flop a turns off! Now, con remembers a low pulse from module a, and so it sends only a high pulse to output.
+
+Push the button a third time:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -high-> inv
+a -high-> con
+inv -low-> b
+con -low-> output
+b -low-> con
+con -high-> output
+This time, flip-flop a turns on, then flip-flop b turns off. However, before b can turn off, the pulse sent to con is handled first, so it briefly remembers all high pulses for its inputs and sends a low pulse to output. After that, flip-flop b turns off, which causes con to update its state and send a high pulse to output.
+
+Finally, with a on and b off, push the button a fourth time:
+
+button -low-> broadcaster
+broadcaster -low-> a
+a -low-> inv
+a -low-> con
+inv -high-> b
+con -high-> output
+This completes the cycle: a turns off, causing con to remember only low pulses and restoring all modules to their original states.
+
+To get the cables warmed up, the Elves have pushed the button 1000 times. How many pulses got sent as a result (including the pulses sent by the button itself)?
+
+In the first example, the same thing happens every time the button is pushed: 8 low pulses and 4 high pulses are sent. So, after pushing the button 1000 times, 8000 low pulses and 4000 high pulses are sent. Multiplying these together gives 32000000.
+
+In the second example, after pushing the button 1000 times, 4250 low pulses and 2750 high pulses are sent. Multiplying these together gives 11687500.
+
+Consult your module configuration; determine the number of low pulses and high pulses that would be sent after pushing the button 1000 times, waiting for all pulses to be fully handled after each push of the button. What do you get if you multiply the total number of low pulses sent by the total number of high pulses sent?
+
+--- Part Two ---
+The final machine responsible for moving the sand down to Island Island has a module attached named rx. The machine turns on when a single low pulse is sent to rx.
+
+Reset all modules to their default states. Waiting for all pulses to be fully handled after each button press, what is the fewest number of button presses required to deliver a single low pulse to the module named rx?*/
\ No newline at end of file
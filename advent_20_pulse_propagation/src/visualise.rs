@@ -0,0 +1,40 @@
+use aoc_common::render::{Color, Frame, Simulate, Stepper};
+
+use crate::{Day20, Pulse, Schema, Sent};
+
+// One button press per step, with every pulse it sent in the order they arrived, high ones in red
+impl Simulate for Day20 {
+    fn stepper(schema: Schema) -> Box<dyn Stepper> {
+        Box::new(Presses { schema, presses: 0, pulses: Vec::new(), totals: [0, 0] })
+    }
+}
+
+struct Presses {
+    schema: Schema,
+    presses: u64,
+    // Sent by the last press
+    pulses: Vec<Sent>,
+    totals: [u64; 2],
+}
+
+impl Stepper for Presses {
+    fn step(&mut self) -> bool {
+        self.pulses = self.schema.press();
+        for sent in &self.pulses {
+            self.totals[sent.pulse as usize] += 1;
+        }
+        self.presses += 1;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let lines = self.pulses.iter()
+            .map(|Sent { from, to, pulse, .. }| match pulse {
+                Pulse::Low => (format!("{from} -low-> {to}"), Color::BLUE),
+                Pulse::High => (format!("{from} -high-> {to}"), Color::RED),
+            })
+            .collect::<Vec<_>>();
+        let caption = format!("Button pressed {} times, {} low and {} high pulses in all, {} in the last press", self.presses, self.totals[0], self.totals[1], lines.len());
+        Frame::text(caption, &lines)
+    }
+}
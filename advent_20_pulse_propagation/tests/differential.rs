@@ -0,0 +1,83 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use advent_20_pulse_propagation::{generate::{counters, tangled}, Day20};
+use aoc_common::{testing::{differential, parse, part2}, Solution};
+
+// Presses the button until `rx` gets a low pulse, or until every module is back in a state it was in
+// before and it never will
+fn brute_force(input: &str) -> Option<u64> {
+    let modules = input.lines()
+        .map(|line| {
+            let (module, outputs) = line.split_once(" -> ").unwrap();
+            let (kind, name) = match module.as_bytes()[0] {
+                b'%' | b'&' => (module.as_bytes()[0], &module[1..]),
+                _ => (b'b', module),
+            };
+            (name, (kind, outputs.split(", ").collect::<Vec<_>>()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut on = HashMap::<&str, bool>::new();
+    let mut memory = HashMap::<(&str, &str), bool>::new();
+    let mut seen = HashSet::new();
+    for presses in 1.. {
+        let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((from, to, high)) = queue.pop_front() {
+            if to == "rx" && !high {
+                return Some(presses);
+            }
+
+            let Some((kind, outputs)) = modules.get(to) else {
+                continue;
+            };
+            let sent = match kind {
+                b'%' if high => continue,
+                b'%' => {
+                    let state = on.entry(to).or_default();
+                    *state = !*state;
+                    *state
+                },
+                b'&' => {
+                    memory.insert((to, from), high);
+                    let inputs = modules.iter().filter(|(_, (_, outputs))| outputs.contains(&to));
+                    !inputs.into_iter().all(|(input, _)| memory.get(&(to, *input)).copied().unwrap_or(false))
+                },
+                _ => high,
+            };
+            queue.extend(outputs.iter().map(|output| (to, *output, sent)));
+        }
+
+        let mut state = on.iter().filter(|(_, on)| **on).map(|(name, _)| (*name, "")).collect::<Vec<_>>();
+        state.extend(memory.iter().filter(|(_, high)| **high).map(|(pair, _)| *pair));
+        state.sort();
+        if !seen.insert(state) {
+            return None;
+        }
+    }
+
+    None
+}
+
+#[test]
+fn lcm_matches_pressing_the_button() {
+    differential(0..20, |seed| counters(seed, 1 + seed as usize % 3, 5), part2::<Day20>, |input| brute_force(input).unwrap().to_string());
+}
+
+// Part 2 has to refuse wiring the LCM doesn't fit, and whatever it answers has to be right
+#[test]
+fn lcm_is_right_or_refused_on_any_wiring() {
+    let mut answered = 0;
+    let mut wrong = Vec::new();
+    for seed in 0..100 {
+        let input = tangled(seed, 1 + seed as usize % 3);
+        if let Ok(presses) = Day20::part2(&parse::<Day20>(&input)) {
+            answered += 1;
+            if brute_force(&input) != Some(presses.to_string().parse().unwrap()) {
+                wrong.push(seed);
+            }
+        }
+    }
+
+    assert!(wrong.is_empty(), "wrong answers for seeds {wrong:?}");
+    assert!((1..100).contains(&answered), "{answered} of 100 wirings answered");
+}
@@ -0,0 +1,15 @@
+use advent_20_pulse_propagation::Day20;
+use aoc_common::testing::part1;
+
+const EXAMPLE_1: &str = include_str!("../fixtures/example_1.txt");
+const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+
+#[test]
+fn part1_first_example() {
+    assert_eq!(part1::<Day20>(EXAMPLE_1), "32000000");
+}
+
+#[test]
+fn part1_more_interesting_example() {
+    assert_eq!(part1::<Day20>(EXAMPLE_2), "11687500");
+}
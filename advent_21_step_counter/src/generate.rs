@@ -0,0 +1,44 @@
+use std::collections::VecDeque;
+
+use aoc_common::{Grid, Rng};
+
+// A square garden of odd side `size` shaped like the real one: `S` in the middle, the border, the
+// row and column through `S` and a diamond halfway out are free of rocks, and no plot is walled in
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let side = size.max(5) | 1;
+    let middle = side / 2;
+
+    let open = |row: usize, col: usize| {
+        row == 0 || col == 0 || row == side - 1 || col == side - 1 || row == middle || col == middle
+            || row.abs_diff(middle) + col.abs_diff(middle) == middle
+    };
+
+    let mut garden = Grid::filled(side, side, '.');
+    for (row, col) in garden.positions().collect::<Vec<_>>() {
+        if !open(row, col) && rng.chance(0.15) {
+            garden[(row, col)] = '#';
+        }
+    }
+
+    // Plots the elf can't reach turn into rocks
+    let mut reached = Grid::filled(side, side, false);
+    let mut queue = VecDeque::from([(middle, middle)]);
+    reached[(middle, middle)] = true;
+    while let Some(pos) = queue.pop_front() {
+        for next in garden.neighbours4(pos).collect::<Vec<_>>() {
+            if garden[next] == '.' && !reached[next] {
+                reached[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    for pos in garden.positions().collect::<Vec<_>>() {
+        if !reached[pos] {
+            garden[pos] = '#';
+        }
+    }
+
+    garden[(middle, middle)] = 'S';
+    garden.to_string() + "\n"
+}
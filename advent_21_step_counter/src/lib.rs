@@ -0,0 +1,243 @@
+pub mod generate;
+pub mod visualise;
+
+use std::fmt;
+
+use aoc_common::{params, search::{bfs_within, Graph}, trace, Configurable, Coord, Grid, Pos, Print, Result, Solution};
+
+#[derive(Clone, PartialEq)]
+pub struct Schema {
+    pub map: Grid<Type>,
+    pub start: Pos,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum Type {
+    Plot,
+    Rock,
+    Start
+}
+
+impl Type {
+    fn new(c: char) -> Option<Type> {
+        match c {
+            '.' => Some(Type::Plot),
+            '#' => Some(Type::Rock),
+            'S' => Some(Type::Start),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tile = match self {
+            Type::Plot => '.',
+            Type::Rock => '#',
+            Type::Start => 'S',
+        };
+        write!(f, "{tile}")
+    }
+}
+
+params! {
+    // Steps the elf takes on the garden of part 1 and the infinite one of part 2
+    pub struct Day21Params {
+        steps_pt1: usize = 64,
+        steps_pt2: usize = 26_501_365,
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Schema;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Schema> {
+        let map = Grid::parse(input, "`.`, `#` or `S`", Type::new)?;
+        let start = map.find(|typ| *typ == Type::Start).ok_or("No starting position `S` on the map")?;
+
+        Ok(Schema { map, start })
+    }
+
+    fn part1(schema: &Schema) -> Result<usize> {
+        Self::part1_with(schema, &Day21Params::default())
+    }
+
+    fn part2(schema: &Schema) -> Result<isize> {
+        Self::part2_with(schema, &Day21Params::default())
+    }
+}
+
+impl Configurable for Day21 {
+    type Params = Day21Params;
+
+    fn part1_with(schema: &Schema, params: &Day21Params) -> Result<usize> {
+        Ok(reachable_plots(schema, params.steps_pt1))
+    }
+
+    fn part2_with(schema: &Schema, params: &Day21Params) -> Result<isize> {
+        let steps = isize::try_from(params.steps_pt2).map_err(|_| format!("{} steps are too many to count", params.steps_pt2))?;
+        reachable_plots_infinite(schema, steps)
+    }
+}
+
+impl Print for Day21 {
+    fn print(schema: &Schema) -> String {
+        schema.map.to_string()
+    }
+}
+
+pub fn reachable_plots(schema: &Schema, steps: usize) -> usize {
+    reachable(schema, steps).len()
+}
+
+// Plots the elf can end up on after exactly `steps` steps. Stepping back and forth wastes two steps,
+// so those are the plots at most `steps` away with as many steps to spare
+fn reachable(schema: &Schema, steps: usize) -> Vec<Pos> {
+    bfs_within(schema, schema.start, steps).iter()
+        .filter(|(_, distance)| distance % 2 == steps % 2)
+        .map(|(&pos, _)| pos)
+        .collect()
+}
+
+impl Graph for Schema {
+    type Node = Pos;
+
+    fn neighbours(&self, &pos: &Pos) -> Vec<(Pos, usize)> {
+        self.map.neighbours4(pos).filter(|next| self.map[*next] != Type::Rock).map(|next| (next, 1)).collect()
+    }
+}
+
+// The map repeats in every direction
+struct Infinite<'a>(&'a Schema);
+
+impl Graph for Infinite<'_> {
+    type Node = Coord;
+
+    fn neighbours(&self, v: &Coord) -> Vec<(Coord, usize)> {
+        let height = self.0.map.rows() as i64;
+        let width = self.0.map.cols() as i64;
+
+        v.neighbours4()
+            .filter(|pos| self.0.map[(pos.row.rem_euclid(height) as usize, pos.col.rem_euclid(width) as usize)] != Type::Rock)
+            .map(|pos| (pos, 1))
+            .collect()
+    }
+}
+
+// Only holds for a square garden with the start in its middle, like the puzzle's
+pub fn reachable_plots_infinite(schema: &Schema, steps: isize) -> Result<isize> {
+    let (rows, cols) = (schema.map.rows(), schema.map.cols());
+    if rows != cols {
+        return Err(format!("The garden has to be square to repeat, not {rows} by {cols}").into());
+    }
+    if schema.start != (rows / 2, cols / 2) {
+        return Err(format!("The start has to be in the middle of the garden at {:?}, not at {:?}", (rows / 2, cols / 2), schema.start).into());
+    }
+
+    let height = rows as isize;
+    let remainder = steps % height;
+
+    // The count grows quadratically every `height` steps, three of them give the curve
+    let first = if remainder == 0 && steps > 0 { height } else { remainder };
+    let samples = [first, first + height, first + 2 * height];
+    let reached = bfs_within(&Infinite(schema), Coord::new(schema.start.0 as i64, schema.start.1 as i64), samples[2] as usize);
+    let [c0, c1, c2] = samples.map(|s| reached.iter().filter(|&(_, distance)| distance as isize <= s && distance as isize % 2 == s % 2).count() as isize);
+    for (steps, plots) in samples.iter().zip([c0, c1, c2]) {
+        trace!(trace::STEP, "sample", "steps": steps, "plots": plots);
+    }
+    let values = [c0, c1 - c0, (c2 - c1) - (c1 - c0)];
+
+    let a = values[2] / 2;
+    let b = values[1] - 3 * a;
+    let c = values[0] - a - b;
+
+    // The first sample is the curve at 1
+    let n = (steps - first) / height + 1;
+    trace!(trace::MILESTONE, "quadratic", "a": a, "b": b, "c": c, "at": n);
+
+    Ok(a * n * n + b * n + c)
+}
+
+/*--- Day 21: Step Counter ---
+You manage to catch the airship right as it's dropping someone else off on their all-expenses-paid trip to Desert Island! It even helpfully drops you off near the gardener and his massive farm.
+
+"You got the sand flowing again! Great work! Now we just need to wait until we have enough sand to filter the water for Snow Island and we'll have snow again in no time."
+
+While you wait, one of the Elves that works with the gardener heard how good you are at solving problems and would like your help. He needs to get his steps in for the day, and so he'd like to know which garden plots he can reach with exactly his remaining 64 steps.
+
+He gives you an up-to-date map (your puzzle input) of his starting position (S), garden plots (.), and rocks (#). For example:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+The Elf starts at the starting position (S) which also counts as a garden plot. Then, he can take one step north, south, east, or west, but only onto tiles that are garden plots. This would allow him to reach any of the tiles marked O:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#O#....
+.##.OS####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+Then, he takes a second step. Since at this point he could be at either tile marked O, his second step would allow him to reach any garden plot that is one step north, south, east, or west of any tile that he could have reached after the first step:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#O..#..
+....#.#....
+.##O.O####.
+.##.O#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+After two steps, he could be at any of the tiles marked O above, including the starting position (either by going north-then-south or by going west-then-east).
+
+A single third step leads to even more possibilities:
+
+...........
+.....###.#.
+.###.##..#.
+..#.#.O.#..
+...O#O#....
+.##.OS####.
+.##O.#...#.
+....O..##..
+.##.#.####.
+.##..##.##.
+...........
+He will continue like this until his steps for the day have been exhausted. After a total of 6 steps, he could reach any of the garden plots marked O:
+
+...........
+.....###.#.
+.###.##.O#.
+.O#O#O.O#..
+O.O.#.#.O..
+.##O.O####.
+.##.O#O..#.
+.O.O.O.##..
+.##.#.####.
+.##O.##.##.
+...........
+In this example, if the Elf's goal was to get exactly 6 more steps today, he could use them to reach any of 16 garden plots.
+
+However, the Elf actually needs to get 64 steps today, and the map he's handed you is much larger than the example map.
+
+Starting from the garden plot marked S on your map, how many garden plots could the Elf reach in exactly 64 steps?*/
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use aoc_common::{render::{Color, Frame, Simulate, Stepper, Style, Visualise}, search::Bfs, Pos};
+
+use crate::{reachable, Day21, Day21Params, Schema, Type};
+
+// The garden with the plots reachable in exactly the part 1 number of steps marked `O`
+impl Visualise for Day21 {
+    fn frames(schema: &Schema) -> Vec<Frame> {
+        let steps = Day21Params::default().steps_pt1;
+        let plots = reachable(schema, steps).into_iter().collect::<HashSet<_>>();
+
+        vec![frame(format!("{} plots reachable in {steps} steps", plots.len()), schema, &plots, &HashSet::new())]
+    }
+}
+
+// The elf's reach growing a step at a time, the plots first reached on the last step in yellow
+impl Simulate for Day21 {
+    fn stepper(schema: Schema) -> Box<dyn Stepper> {
+        let search = Bfs::new(schema.start);
+        Box::new(Walk { schema, search, steps: 0 })
+    }
+}
+
+struct Walk {
+    schema: Schema,
+    search: Bfs<Pos>,
+    // Keeps counting once the whole garden is reached, the elf can still step back and forth
+    steps: usize,
+}
+
+impl Stepper for Walk {
+    fn step(&mut self) -> bool {
+        if self.search.distance() == self.steps {
+            _ = self.search.step(&self.schema);
+        }
+        self.steps += 1;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        let plots = self.search.reached().iter()
+            .filter(|(_, distance)| distance % 2 == self.steps % 2)
+            .map(|(&pos, _)| pos)
+            .collect::<HashSet<_>>();
+        let frontier = match self.search.distance() == self.steps {
+            true => self.search.frontier().iter().copied().collect(),
+            false => HashSet::new(),
+        };
+
+        frame(format!("{} plots reachable in {} steps", plots.len(), self.steps), &self.schema, &plots, &frontier)
+    }
+}
+
+fn frame(caption: String, schema: &Schema, plots: &HashSet<Pos>, frontier: &HashSet<Pos>) -> Frame {
+    Frame::new(caption, &schema.map, |pos, tile| match tile {
+        Type::Rock => Style::new('#', Color::WHITE),
+        _ if frontier.contains(&pos) => Style::new('O', Color::YELLOW),
+        _ if plots.contains(&pos) => Style::new('O', Color::GREEN),
+        Type::Start => Style::new('S', Color::RED),
+        Type::Plot => Style::new('.', Color::GREY),
+    })
+}
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+use advent_21_step_counter::{generate::generate, reachable_plots_infinite, Day21};
+use aoc_common::testing::{differential, parse};
+
+// Every reachable plot of the repeating garden, one step at a time
+fn brute_force(input: &str, steps: usize) -> isize {
+    let rows = input.lines().map(|line| line.as_bytes()).collect::<Vec<_>>();
+    let side = rows.len() as isize;
+    let start = (side / 2, side / 2);
+
+    let mut reached = HashSet::from([start]);
+    for _ in 0..steps {
+        reached = reached.iter()
+            .flat_map(|&(row, col)| [(row - 1, col), (row + 1, col), (row, col - 1), (row, col + 1)])
+            .filter(|&(row, col)| rows[row.rem_euclid(side) as usize][col.rem_euclid(side) as usize] != b'#')
+            .collect();
+    }
+
+    reached.len() as isize
+}
+
+// The puzzle's shape of step count, half a garden plus a whole number of gardens, along with whole
+// numbers of gardens and counts that stay inside the first garden
+fn steps(input: &str) -> Vec<usize> {
+    let side = input.lines().count();
+    vec![side / 2 + 4 * side, side / 2 + side, 4 * side, 2 * side, side, side / 2, 1, 0]
+}
+
+#[test]
+fn quadratic_matches_brute_force() {
+    differential(
+        0..20,
+        |seed| generate(seed, 7 + 2 * (seed as usize % 4)),
+        |input| steps(input).into_iter().map(|steps| reachable_plots_infinite(&parse::<Day21>(input), steps as isize).unwrap()).collect::<Vec<_>>(),
+        |input| steps(input).into_iter().map(|steps| brute_force(input, steps)).collect::<Vec<_>>(),
+    );
+}
@@ -0,0 +1,19 @@
+use advent_21_step_counter::{reachable_plots, Day21};
+use aoc_common::{testing::parse, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+// The example map is walked for 6 steps instead of 64
+#[test]
+fn part1_example() {
+    assert_eq!(reachable_plots(&parse::<Day21>(EXAMPLE), 6), 16);
+}
+
+// The quadratic only fits gardens repeating around a start in their middle
+#[test]
+fn part2_refuses_gardens_it_does_not_fit() {
+    let error = |input: &str| Day21::part2(&parse::<Day21>(input)).unwrap_err().to_string();
+
+    assert_eq!(error("...\n.S.\n...\n..."), "The garden has to be square to repeat, not 4 by 3");
+    assert_eq!(error("S..\n...\n..."), "The start has to be in the middle of the garden at (1, 1), not at (0, 0)");
+}
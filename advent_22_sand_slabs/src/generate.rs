@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+// `size` bricks of up to four cubes in a 10x10 column, snapshotted mid-air at random heights
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let height = 2 * size as u64 + 10;
+
+    let mut taken = HashSet::new();
+    let mut bricks = Vec::new();
+    while bricks.len() < size {
+        let start = [rng.range(0..10), rng.range(0..10), rng.range(1..=height)];
+        let axis = rng.below(3);
+        let mut end = start;
+        end[axis] += rng.range(0..4);
+        if end[0] > 9 || end[1] > 9 {
+            continue;
+        }
+
+        let cubes = (start[axis]..=end[axis]).map(|i| {
+            let mut cube = start;
+            cube[axis] = i;
+            cube
+        }).collect::<Vec<_>>();
+
+        if cubes.iter().all(|cube| !taken.contains(cube)) {
+            taken.extend(cubes);
+            bricks.push(format!("{},{},{}~{},{},{}", start[0], start[1], start[2], end[0], end[1], end[2]));
+        }
+    }
+
+    bricks.join("\n") + "\n"
+}
@@ -0,0 +1,14 @@
+use advent_22_sand_slabs::Day22;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day22>(EXAMPLE), "5");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day22>(EXAMPLE), "7");
+}
@@ -0,0 +1,120 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{Grid, Pos, Rng};
+
+// A forest of odd side `size` with a path in from the top left and out at the bottom right. Like
+// the real input the trails meet at junctions, there are no dead ends, and slopes on both ends of
+// every trail between two junctions only let you walk it away from the start. Part 2 tries every
+// route, so keep `size` small
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let cells = size.max(5).saturating_sub(1) / 2;
+
+    // Cell (r, c) is tile (2r + 1, 2c + 1), the tile between two linked cells is a path too
+    let mut links = Grid::filled(cells, cells, Vec::<Pos>::new());
+    let mut seen = Grid::filled(cells, cells, false);
+    let mut stack = vec![(0, 0)];
+    seen[(0, 0)] = true;
+    while let Some(&pos) = stack.last() {
+        let next = links.neighbours4(pos).filter(|&n| !seen[n]).collect::<Vec<_>>();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let next = *rng.choose(&next);
+        link(&mut links, pos, next);
+        seen[next] = true;
+        stack.push(next);
+    }
+
+    // Dead ends get a second way out, and a few more shortcuts make for several routes
+    for pos in links.positions().collect::<Vec<_>>() {
+        let unlinked = links.neighbours4(pos).filter(|n| !links[pos].contains(n)).collect::<Vec<_>>();
+        if !unlinked.is_empty() && (links[pos].len() == 1 || rng.chance(0.1)) {
+            let other = *rng.choose(&unlinked);
+            link(&mut links, pos, other);
+        }
+    }
+
+    let mut tiles = Grid::filled(2 * cells + 1, 2 * cells + 1, '#');
+    let tile = |(row, col): Pos| (2 * row + 1, 2 * col + 1);
+    for (pos, linked) in links.iter() {
+        tiles[tile(pos)] = '.';
+        for &other in linked {
+            tiles[(pos.0 + other.0 + 1, pos.1 + other.1 + 1)] = '.';
+        }
+    }
+    let (start, end) = ((0, 0), (cells - 1, cells - 1));
+    tiles[(0, 1)] = '.';
+    tiles[(2 * cells, 2 * cells - 1)] = '.';
+
+    // Junctions are cells with three or more ways out, the entrance and the exit count as one
+    let degree = |pos: Pos| links[pos].len() + usize::from(pos == start || pos == end);
+    let junctions = links.positions().filter(|&pos| degree(pos) >= 3).collect::<Vec<_>>();
+
+    // Trails are oriented by the distance of their junctions from the start, which keeps part 1 acyclic
+    let distance = distances(&links, start);
+    for &junction in &junctions {
+        for &first in &links[junction] {
+            let trail = trail(&links, junction, first, &|pos| degree(pos) >= 3);
+            let other = *trail.last().expect("a trail has at least one cell");
+            if degree(other) < 3 || (distance[&other], other) < (distance[&junction], junction) {
+                continue;
+            }
+
+            // Slopes on the tiles right after the first junction and right before the second
+            let cells = [junction].into_iter().chain(trail).collect::<Vec<_>>();
+            let (leave, arrive) = ((cells[0], cells[1]), (cells[cells.len() - 2], cells[cells.len() - 1]));
+            for (from, to) in [leave, arrive] {
+                tiles[(from.0 + to.0 + 1, from.1 + to.1 + 1)] = slope(from, to);
+            }
+        }
+    }
+
+    tiles.to_string() + "\n"
+}
+
+fn link(links: &mut Grid<Vec<Pos>>, a: Pos, b: Pos) {
+    links[a].push(b);
+    links[b].push(a);
+}
+
+// Cells from `first` on, walking away from `from` until a cell `stop` accepts
+fn trail(links: &Grid<Vec<Pos>>, from: Pos, first: Pos, stop: &dyn Fn(Pos) -> bool) -> Vec<Pos> {
+    let (mut previous, mut current) = (from, first);
+    let mut cells = vec![first];
+    while !stop(current) && current != from {
+        let Some(&next) = links[current].iter().find(|&&n| n != previous) else {
+            break;
+        };
+        (previous, current) = (current, next);
+        cells.push(current);
+    }
+
+    cells
+}
+
+fn distances(links: &Grid<Vec<Pos>>, start: Pos) -> HashMap<Pos, usize> {
+    let mut distance = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(pos) = queue.pop_front() {
+        for &next in &links[pos] {
+            if !distance.contains_key(&next) {
+                distance.insert(next, distance[&pos] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distance
+}
+
+fn slope(from: Pos, to: Pos) -> char {
+    match (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize) {
+        (1, 0) => 'v',
+        (-1, 0) => '^',
+        (0, 1) => '>',
+        _ => '<',
+    }
+}
@@ -0,0 +1,206 @@
+pub mod generate;
+
+use std::{collections::{HashMap, HashSet}, fmt};
+use aoc_common::{search::{longest_path, Graph}, Dir4, Grid, Pos, Print, Result, Solution};
+
+#[derive(Clone, PartialEq)]
+pub struct Map {
+    pub tiles: Grid<Type>,
+    pub start: Pos,
+    pub end: Pos,
+}
+
+impl Map {
+    // Part 2 treats every slope as a regular path
+    fn without_hills(&self) -> Map {
+        let tiles = self.tiles.map(|typ| match typ {
+            Type::Hill(_) => Type::Path,
+            typ => *typ,
+        });
+        Map { tiles, ..self.clone() }
+    }
+
+    // Tiles to step onto next, slopes only downhill
+    fn get_neighbors(&self, curr: Pos, visited: &HashSet<Pos>) -> Vec<Pos> {
+        Dir4::ALL.into_iter().filter_map(|dir| {
+            let new_pos = self.tiles.step(curr, dir).filter(|new_pos| !visited.contains(new_pos))?;
+            match self.tiles[new_pos] {
+                Type::Path => Some(new_pos),
+                Type::Hill(slope) if slope == dir => Some(new_pos),
+                _ => None
+            }
+        }).collect::<Vec<_>>()
+    }
+}
+
+// The map shrunk to its junctions, the trails between them walked once up front
+struct Trails(HashMap<Pos, Vec<(Pos, usize)>>);
+
+impl Trails {
+    fn new(map: &Map) -> Trails {
+        let is_junction = |pos: Pos| pos == map.start || pos == map.end
+            || map.tiles.neighbours4(pos).filter(|&next| map.tiles[next] != Type::Forest).count() > 2;
+        let junctions = map.tiles.iter().filter(|&(pos, typ)| *typ != Type::Forest && is_junction(pos)).map(|(pos, _)| pos);
+
+        let trails = junctions.map(|junction| {
+            // Follows every trail leaving the junction to the next one, trails running into a dead end or
+            // against a slope lead nowhere
+            let ends = map.get_neighbors(junction, &HashSet::new()).into_iter().filter_map(|first| {
+                let (mut prev, mut curr, mut length) = (junction, first, 1);
+                while !is_junction(curr) {
+                    let next = *map.get_neighbors(curr, &HashSet::from([prev])).first()?;
+                    (prev, curr, length) = (curr, next, length + 1);
+                }
+
+                Some((curr, length))
+            }).collect();
+
+            (junction, ends)
+        }).collect();
+
+        Trails(trails)
+    }
+}
+
+impl Graph for Trails {
+    type Node = Pos;
+
+    fn neighbours(&self, pos: &Pos) -> Vec<(Pos, usize)> {
+        self.0.get(pos).cloned().unwrap_or_default()
+    }
+}
+
+// The most steps from the start to the end without stepping on a tile twice
+fn longest_hike(map: &Map) -> Result<i64> {
+    let (_, steps) = longest_path(&Trails::new(map), map.start, &map.end).ok_or("No hike reaches the end")?;
+    Ok(steps as i64)
+}
+
+#[derive(Clone, PartialEq, Eq, Copy)]
+pub enum Type {
+    Forest,
+    Path,
+    Hill(Dir4)
+}
+
+impl Type {
+    fn new (c: char)-> Option<Type> {
+        match c {
+            '#' => Some(Type::Forest),
+            '.' => Some(Type::Path),
+            '>' => Some(Type::Hill(Dir4::East)),
+            '<' => Some(Type::Hill(Dir4::West)),
+            'v' => Some(Type::Hill(Dir4::South)),
+            '^' => Some(Type::Hill(Dir4::North)),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tile = match self {
+            Type::Forest => '#',
+            Type::Path => '.',
+            Type::Hill(slope) => slope.arrow(),
+        };
+        write!(f, "{tile}")
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Map> {
+        let tiles = Grid::parse(input, "`.`, `#` or a slope", Type::new)?;
+
+        let mut paths = tiles.iter().filter(|(_, typ)| **typ != Type::Forest).map(|(pos, _)| pos);
+        let start = paths.next().ok_or("No paths on the map")?;
+        let end = paths.last().unwrap_or(start);
+
+        Ok(Map { tiles, start, end })
+    }
+
+    fn part1(map: &Map) -> Result<i64> {
+        longest_hike(map)
+    }
+
+    fn part2(map: &Map) -> Result<i64> {
+        longest_hike(&map.without_hills())
+    }
+}
+
+impl Print for Day23 {
+    fn print(map: &Map) -> String {
+        map.tiles.to_string()
+    }
+}
+
+/*--- Day 23: A Long Walk ---
+The Elves resume water filtering operations! Clean water starts flowing over the edge of Island Island.
+
+They offer to help you go over the edge of Island Island, too! Just hold on tight to one end of this impossibly long rope and they'll lower you down a safe distance from the massive waterfall you just created.
+
+As you finally reach Snow Island, you see that the water isn't really reaching the ground: it's being absorbed by the air itself. It looks like you'll finally have a little downtime while the moisture builds up to snow-producing levels. Snow Island is pretty scenic, even without any snow; why not take a walk?
+
+There's a map of nearby hiking trails (your puzzle input) that indicates paths (.), forest (#), and steep slopes (^, >, v, and <).
+
+For example:
+
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+You're currently on the single path tile in the top row; your goal is to reach the single path tile in the bottom row. Because of all the mist from the waterfall, the slopes are probably quite icy; if you step onto a slope tile, your next step must be downhill (in the direction the arrow is pointing). To make sure you have the most scenic hike possible, never step onto the same tile twice. What is the longest hike you can take?
+
+In the example above, the longest hike you can take is marked with O, and your starting position is marked S:
+
+#S#####################
+#OOOOOOO#########...###
+#######O#########.#.###
+###OOOOO#OOO>.###.#.###
+###O#####O#O#.###.#.###
+###OOOOO#O#O#.....#...#
+###v###O#O#O#########.#
+###...#O#O#OOOOOOO#...#
+#####.#O#O#######O#.###
+#.....#O#O#OOOOOOO#...#
+#.#####O#O#O#########v#
+#.#...#OOO#OOO###OOOOO#
+#.#.#v#######O###O###O#
+#...#.>.#...>OOO#O###O#
+#####v#.#.###v#O#O###O#
+#.....#...#...#O#O#OOO#
+#.#########.###O#O#O###
+#...###...#...#OOO#O###
+###.###.#.###v#####O###
+#...#...#.#.>.>.#.>O###
+#.###.###.#.###.#.#O###
+#.....###...###...#OOO#
+#####################O#
+This hike contains 94 steps. (The other possible hikes you could have taken were 90, 86, 82, 82, and 74 steps long.)
+
+Find the longest hike you can take through the hiking trails listed on your map. How many steps long is the longest hike?*/
\ No newline at end of file
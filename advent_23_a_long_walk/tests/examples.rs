@@ -0,0 +1,14 @@
+use advent_23_a_long_walk::Day23;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day23>(EXAMPLE), "94");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day23>(EXAMPLE), "154");
+}
@@ -0,0 +1,18 @@
+use aoc_common::Rng;
+
+// `size` games of one to six draws, each showing up to 20 cubes of some of the colors
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (1..=size).map(|id| {
+        let sets = (0..rng.range(1..=6)).map(|_| {
+            let mut colors = vec!["red", "green", "blue"];
+            rng.shuffle(&mut colors);
+            colors.truncate(rng.range(1..=3) as usize);
+
+            colors.iter().map(|color| format!("{} {color}", rng.range(1..=20))).collect::<Vec<_>>().join(", ")
+        }).collect::<Vec<_>>();
+
+        format!("Game {id}: {}\n", sets.join("; "))
+    }).collect()
+}
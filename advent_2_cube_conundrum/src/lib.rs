@@ -0,0 +1,200 @@
+pub mod generate;
+
+use std::fmt;
+
+use aoc_common::{params, trace, Configurable, ParseResult, Print, Result, Solution, Source};
+
+#[derive(PartialEq, Debug)]
+pub struct Set{
+    pub blue: i32, 
+    pub red: i32, 
+    pub green: i32
+}
+
+#[derive(PartialEq)]
+pub struct Game {
+    pub id: i32,
+    pub sets: Vec<Set>
+}
+
+params! {
+    // The cubes in the bag for part 1
+    pub struct Day2Params {
+        red: i32 = 12,
+        green: i32 = 13,
+        blue: i32 = 14,
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        let source = Source::new(input);
+
+        let games = input.lines().map(|line| {
+            let (game, rounds) = source.split_once(line, ":")?;
+
+            let id = source.number(source.token(game, 1, "a game id")?)?;
+            let sets = rounds.split(';').map(|r| to_set(&source, r)).collect::<ParseResult<Vec<Set>>>()?;
+
+            Ok(Game { id, sets })
+        }).collect::<ParseResult<Vec<_>>>()?;
+
+        Ok(games)
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<i32> {
+        Self::part1_with(games, &Day2Params::default())
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<i32> {
+        Self::part2_with(games, &Day2Params::default())
+    }
+}
+
+impl Configurable for Day2 {
+    type Params = Day2Params;
+
+    fn part1_with(games: &Vec<Game>, bag: &Day2Params) -> Result<i32> {
+        let mut id_total = 0;
+
+        for game in games {
+            let (blues, reds, greens) = game.max_cubes();
+            let possible = bag.blue >= blues && bag.red >= reds && bag.green >= greens;
+            trace!(trace::STEP, "game", "id": game.id, "red": reds, "green": greens, "blue": blues, "possible": possible);
+
+            if possible {
+                id_total = game.id.checked_add(id_total).ok_or("The game ids add up past what an i32 holds")?;
+            }
+        }
+
+        Ok(id_total)
+    }
+
+    fn part2_with(games: &Vec<Game>, _: &Day2Params) -> Result<i32> {
+        let mut power_total = 0;
+
+        for game in games {
+            let (blues, reds, greens) = game.max_cubes();
+
+            let power = blues.checked_mul(reds).and_then(|power| power.checked_mul(greens))
+                .ok_or_else(|| format!("The power of game {} is past what an i32 holds", game.id))?;
+            trace!(trace::STEP, "power", "id": game.id, "red": reds, "green": greens, "blue": blues, "power": power);
+            power_total = power.checked_add(power_total).ok_or("The powers add up past what an i32 holds")?;
+        }
+
+        Ok(power_total)
+    }
+}
+
+impl Print for Day2 {
+    fn print(games: &Vec<Game>) -> String {
+        games.iter().map(Game::to_string).collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl Game {
+    // A game without sets needs no cubes at all
+    fn max_cubes(&self) -> (i32, i32, i32) {
+        let blues = self.sets.iter().map(|set| set.blue).max().unwrap_or(0);
+        let reds = self.sets.iter().map(|set| set.red).max().unwrap_or(0);
+        let greens = self.sets.iter().map(|set| set.green).max().unwrap_or(0);
+
+        (blues, reds, greens)
+    }
+}
+
+fn to_set(source: &Source, s: &str) -> ParseResult<Set> {
+    let mut blue = 0;
+    let mut red = 0;
+    let mut green = 0;
+
+    for cube in s.split(',') {
+        let count = source.number::<i32>(source.token(cube, 0, "a cube count")?)?;
+        let color = source.token(cube, 1, "a cube color")?;
+
+        match color {
+            "blue" => blue = count,
+            "red" => red = count,
+            "green" => green = count,
+            _ => return Err(source.unexpected(color, "`red`, `green` or `blue`"))
+        }
+    }
+
+    Ok(Set { blue, red, green})
+}
+
+// Only the colors drawn, a draw of nothing as no red cubes
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = [(self.red, "red"), (self.green, "green"), (self.blue, "blue")].into_iter()
+            .filter(|(count, _)| *count != 0)
+            .map(|(count, color)| format!("{count} {color}"))
+            .collect::<Vec<_>>();
+
+        if cubes.is_empty() {
+            write!(f, "0 red")
+        } else {
+            write!(f, "{}", cubes.join(", "))
+        }
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets = self.sets.iter().map(Set::to_string).collect::<Vec<_>>();
+        write!(f, "Game {}: {}", self.id, sets.join("; "))
+    }
+}
+
+/* --- Day 2: Cube Conundrum ---
+You're launched high into the atmosphere! The apex of your trajectory just barely reaches the surface of a large island floating in the sky. You gently land in a fluffy pile of leaves. It's quite cold, but you don't see much snow. An Elf runs over to greet you.
+
+The Elf explains that you've arrived at Snow Island and apologizes for the lack of snow. He'll be happy to explain the situation, but it's a bit of a walk, so you have some time. They don't get many visitors up here; would you like to play a game in the meantime?
+
+As you walk, the Elf shows you a small bag and some cubes which are either red, green, or blue. Each time you play this game, he will hide a secret number of cubes of each color in the bag, and your goal is to figure out information about the number of cubes.
+
+To get information, once a bag has been loaded with cubes, the Elf will reach into the bag, grab a handful of random cubes, show them to you, and then put them back in the bag. He'll do this a few times per game.
+
+You play several games and record the information from each game (your puzzle input). Each game is listed with its ID number (like the 11 in Game 11: ...) followed by a semicolon-separated list of subsets of cubes that were revealed from the bag (like 3 red, 5 green, 4 blue).
+
+For example, the record of a few games might look like this:
+
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+In game 1, three sets of cubes are revealed from the bag (and then put back again). The first set is 3 blue cubes and 4 red cubes; the second set is 1 red cube, 2 green cubes, and 6 blue cubes; the third set is only 2 green cubes.
+
+The Elf would first like to know which games would have been possible if the bag contained only 12 red cubes, 13 green cubes, and 14 blue cubes?
+
+In the example above, games 1, 2, and 5 would have been possible if the bag had been loaded with that configuration. However, game 3 would have been impossible because at one point the Elf showed you 20 red cubes at once; similarly, game 4 would also have been impossible because the Elf showed you 15 blue cubes at once. If you add up the IDs of the games that would have been possible, you get 8.
+
+Determine which games would have been possible if the bag had been loaded with only 12 red cubes, 13 green cubes, and 14 blue cubes. What is the sum of the IDs of those games?
+
+--- Part Two ---
+The Elf says they've stopped producing snow because they aren't getting any water! He isn't sure why the water stopped; however, he can show you how to get to the water source to check it out for yourself. It's just up ahead!
+
+As you continue your walk, the Elf poses a second question: in each game you played, what is the fewest number of cubes of each color that could have been in the bag to make the game possible?
+
+Again consider the example games from earlier:
+
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+In game 1, the game could have been played with as few as 4 red, 2 green, and 6 blue cubes. If any color had even one fewer cube, the game would have been impossible.
+Game 2 could have been played with a minimum of 1 red, 3 green, and 4 blue cubes.
+Game 3 must have been played with at least 20 red, 13 green, and 6 blue cubes.
+Game 4 required at least 14 red, 3 green, and 15 blue cubes.
+Game 5 needed no fewer than 6 red, 3 green, and 2 blue cubes in the bag.
+The power of a set of cubes is equal to the numbers of red, green, and blue cubes multiplied together. The power of the minimum set of cubes in game 1 is 48. In games 2-5 it was 12, 1560, 630, and 36, respectively. Adding up these five powers produces the sum 2286.
+
+For each game, find the minimum set of cubes that must have been present. What is the sum of the power of these sets? */
\ No newline at end of file
@@ -0,0 +1,37 @@
+use advent_2_cube_conundrum::{Day2, Day2Params, Game};
+use aoc_common::{testing::{parse, part1, part1_with, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day2>(EXAMPLE), "8");
+}
+
+// Enough cubes for the 20 red of game 3 and the 15 blue of game 4
+#[test]
+fn part1_bigger_bag() {
+    let bag = Day2Params { red: 20, blue: 15, ..Day2Params::default() };
+
+    assert_eq!(part1_with::<Day2>(EXAMPLE, &bag), "15");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day2>(EXAMPLE), "2286");
+}
+
+#[test]
+fn games_without_sets_need_no_cubes() {
+    let games = vec![Game { id: 1, sets: Vec::new() }];
+
+    assert_eq!(Day2::part1(&games).unwrap(), 1);
+    assert_eq!(Day2::part2(&games).unwrap(), 0);
+}
+
+#[test]
+fn powers_past_32_bits_are_an_error() {
+    let error = Day2::part2(&parse::<Day2>("Game 7: 2000 red, 2000 green, 2000 blue\n")).unwrap_err();
+
+    assert_eq!(error.to_string(), "The power of game 7 is past what an i32 holds");
+}
@@ -0,0 +1,29 @@
+use aoc_common::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '*', '*', '#', '+', '$', '/', '=', '%', '@'];
+
+// A `size`x`size` schematic of part numbers up to three digits and symbols, gears included
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size).map(|_| {
+        let mut row = String::with_capacity(size + 1);
+        while row.len() < size {
+            let left = size - row.len();
+            if rng.chance(0.2) {
+                let digits = (rng.range(1..=3) as usize).min(left);
+                row += &rng.range(10u64.pow(digits as u32 - 1)..10u64.pow(digits as u32)).to_string();
+                // Numbers on one row never touch
+                if row.len() < size {
+                    row.push('.');
+                }
+            } else if rng.chance(0.1) {
+                row.push(*rng.choose(&SYMBOLS));
+            } else {
+                row.push('.');
+            }
+        }
+
+        row + "\n"
+    }).collect()
+}
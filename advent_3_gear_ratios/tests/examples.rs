@@ -0,0 +1,20 @@
+use advent_3_gear_ratios::Day3;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day3>(EXAMPLE), "4361");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day3>(EXAMPLE), "467835");
+}
+
+// Each of a repeated number is where it is written, not all at the first
+#[test]
+fn repeated_numbers_keep_their_columns() {
+    assert_eq!(part1::<Day3>("12.12*\n......"), "12");
+}
@@ -0,0 +1,13 @@
+use advent_3_gear_ratios::{generate::generate, Day3};
+use aoc_common::{testing::{parse, round_trip}, Print};
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day3>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
+
+#[test]
+fn repeated_numbers_print_where_they_are() {
+    let schematic = "12.12*\n..12..";
+    assert_eq!(Day3::print(&parse::<Day3>(schematic)), schematic);
+}
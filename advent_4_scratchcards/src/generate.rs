@@ -0,0 +1,22 @@
+use aoc_common::Rng;
+
+const WINNING: usize = 10;
+const NUMBERS: usize = 25;
+
+// `size` cards with 10 winning numbers and 25 numbers you have, no card wins copies past the end of the table
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (1..=size).map(|id| {
+        let mut pool = (1..100).collect::<Vec<u32>>();
+        rng.shuffle(&mut pool);
+
+        let matches = rng.below(WINNING.min(size - id) + 1);
+        let winning = &pool[..WINNING];
+        let mut numbers = [&winning[..matches], &pool[WINNING..WINNING + NUMBERS - matches]].concat();
+        rng.shuffle(&mut numbers);
+
+        let column = |nums: &[u32]| nums.iter().map(|n| format!("{n:>2}")).collect::<Vec<_>>().join(" ");
+        format!("Card {id:>3}: {} | {}\n", column(winning), column(&numbers))
+    }).collect()
+}
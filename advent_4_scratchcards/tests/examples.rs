@@ -0,0 +1,14 @@
+use advent_4_scratchcards::Day4;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day4>(EXAMPLE), "13");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day4>(EXAMPLE), "30");
+}
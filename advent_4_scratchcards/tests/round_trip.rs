@@ -0,0 +1,7 @@
+use advent_4_scratchcards::{generate::generate, Day4};
+use aoc_common::testing::round_trip;
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day4>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
@@ -0,0 +1,50 @@
+use aoc_common::Rng;
+
+const MAPS: [&str; 7] = ["seed-to-soil", "soil-to-fertilizer", "fertilizer-to-water", "water-to-light",
+    "light-to-temperature", "temperature-to-humidity", "humidity-to-location"];
+
+// Every map shuffles `size` ranges of the numbers below 1000 * `size`; the seed ranges stay short
+// so the differential test can still try every seed, and all but the first may be empty
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let span = 1000 * size.max(1) as u64;
+
+    let seeds = (0..4)
+        .map(|i| {
+            let len = if i > 0 && rng.chance(0.2) { 0 } else { rng.range(1..=100) };
+            format!("{} {len}", rng.range(0..span - len))
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut text = format!("seeds: {seeds}\n");
+    for name in MAPS {
+        text += &format!("\n{name} map:\n");
+
+        let mut cuts = (0..size.saturating_sub(1)).map(|_| rng.range(1..span)).collect::<Vec<_>>();
+        cuts.extend([0, span]);
+        cuts.sort();
+        cuts.dedup();
+
+        // Sources are the segments between the cuts, destinations the same segments in shuffled order
+        let mut order = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect::<Vec<_>>();
+        rng.shuffle(&mut order);
+
+        let mut destination = 0;
+        let mut lines = Vec::new();
+        for (source, len) in order {
+            lines.push(format!("{destination} {source} {len}"));
+            destination += len;
+        }
+
+        // Sometimes a range is left out, numbers outside of every range map to themselves
+        rng.shuffle(&mut lines);
+        if lines.len() > 1 && rng.chance(0.5) {
+            lines.pop();
+        }
+        text += &lines.join("\n");
+        text.push('\n');
+    }
+
+    text
+}
@@ -0,0 +1,358 @@
+pub mod generate;
+
+use std::{fmt, vec, ops::Range};
+use aoc_common::{trace, ParseResult, Print, Result, Solution, Source};
+
+#[derive(PartialEq)]
+pub struct TranslationTable {
+    pub seeds: Vec<i64>,
+    pub seed_soil: Vec<RangeMap>,
+    pub soil_fertilizer: Vec<RangeMap>,
+    pub fertilizer_water: Vec<RangeMap>,
+    pub water_light: Vec<RangeMap>,
+    pub light_temperature: Vec<RangeMap>,
+    pub temperature_humidity: Vec<RangeMap>,
+    pub humidity_location: Vec<RangeMap>,
+}
+
+impl TranslationTable {
+    fn new() -> TranslationTable {
+        TranslationTable { seeds: vec![], seed_soil: vec![], soil_fertilizer: vec![], 
+            fertilizer_water: vec![], water_light: vec![], light_temperature: vec![], 
+            temperature_humidity: vec![], humidity_location: vec![]}
+    }
+
+    fn get_seed_location(&self, seed: i64) -> i64 {
+        let mut soil = seed;
+        for range_map in &self.seed_soil {
+            if range_map.range.contains(&seed) {
+                soil = seed + range_map.delta;
+                break;
+            }
+        }
+
+        let mut fertilizer = soil;
+        for range_map in &self.soil_fertilizer {
+            if range_map.range.contains(&soil) {
+                fertilizer = soil + range_map.delta;
+                break;
+            }
+        }
+
+        let mut water = fertilizer;
+        for range_map in &self.fertilizer_water {
+            if range_map.range.contains(&fertilizer) {
+                water = fertilizer + range_map.delta;
+                break;
+            }
+        }
+
+        let mut light = water;
+        for range_map in &self.water_light {
+            if range_map.range.contains(&water) {
+                light = water + range_map.delta;
+                break;
+            }
+        }
+
+        let mut temperature = light;
+        for range_map in &self.light_temperature {
+            if range_map.range.contains(&light) {
+                temperature = light + range_map.delta;
+                break;
+            }
+        }
+
+        let mut humidity = temperature;
+        for range_map in &self.temperature_humidity {
+            if range_map.range.contains(&temperature) {
+                humidity = temperature + range_map.delta;
+                break;
+            }
+        }
+
+        let mut location = humidity;
+        for range_map in &self.humidity_location {
+            if range_map.range.contains(&humidity) {
+                location = humidity + range_map.delta;
+                break;
+            }
+        }
+
+        location
+    }
+
+    fn stages(&self) -> [(&'static str, &[RangeMap]); 7] {
+        [
+            ("seed-to-soil", &self.seed_soil),
+            ("soil-to-fertilizer", &self.soil_fertilizer),
+            ("fertilizer-to-water", &self.fertilizer_water),
+            ("water-to-light", &self.water_light),
+            ("light-to-temperature", &self.light_temperature),
+            ("temperature-to-humidity", &self.temperature_humidity),
+            ("humidity-to-location", &self.humidity_location),
+        ]
+    }
+
+    // Whole ranges through every map at once: a range that straddles the edge of a map's range is split
+    // there, the part inside is shifted and the rest tries the next map or stays as it is
+    fn get_range_locations(&self, seeds: Vec<Range<i64>>) -> Vec<Range<i64>> {
+        let mut ranges = seeds;
+        for (stage, range_maps) in self.stages() {
+            let mut translated = Vec::new();
+            for range in ranges {
+                let mut unmapped = vec![range];
+                for range_map in range_maps {
+                    let mut rest = Vec::new();
+                    for range in unmapped {
+                        let (start, end) = (range.start.max(range_map.range.start), range.end.min(range_map.range.end));
+                        if start >= end {
+                            rest.push(range);
+                            continue;
+                        }
+
+                        let outside = [range.start..start, end..range.end].into_iter().filter(|r| !r.is_empty()).collect::<Vec<_>>();
+                        if !outside.is_empty() {
+                            trace!(trace::STEP, "split", "stage": stage, "range": [range.start, range.end],
+                                "map": [range_map.range.start, range_map.range.end], "inside": [start, end],
+                                "outside": outside.iter().map(|r| [r.start, r.end]).collect::<Vec<_>>());
+                        }
+                        translated.push(start + range_map.delta..end + range_map.delta);
+                        rest.extend(outside);
+                    }
+                    unmapped = rest;
+                }
+                translated.extend(unmapped);
+            }
+            trace!(trace::MILESTONE, "stage", "stage": stage, "ranges": translated.len());
+            ranges = translated;
+        }
+
+        ranges
+    }
+}
+
+#[derive(PartialEq)]
+pub struct RangeMap {
+    pub range: Range<i64>,
+    pub delta: i64,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = TranslationTable;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<TranslationTable> {
+        let src = Source::new(input);
+        let lines = input.lines().collect::<Vec<_>>();
+        let mut groups = lines.split(|line| line.trim().is_empty()).filter(|group| !group.is_empty());
+
+        let mut table = TranslationTable::new();
+        let Some(seeds) = groups.next() else {
+            return Err(src.missing(input, "`seeds:`").into());
+        };
+        let (name, numbers) = src.split_once(seeds[0], ":")?;
+        if name != "seeds" {
+            return Err(src.unexpected(name, "`seeds`").into());
+        }
+        if let Some(extra) = seeds.get(1) {
+            return Err(src.unexpected(extra, "an empty line").into());
+        }
+        table.seeds = numbers.split_whitespace()
+            .map(|s| src.number::<i64>(s))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        for group in groups {
+            let name = group[0].strip_suffix(" map:").ok_or_else(|| src.unexpected(group[0], "a map like `seed-to-soil map:`"))?;
+            let range_maps = match name {
+                "seed-to-soil" => &mut table.seed_soil,
+                "soil-to-fertilizer" => &mut table.soil_fertilizer,
+                "fertilizer-to-water" => &mut table.fertilizer_water,
+                "water-to-light" => &mut table.water_light,
+                "light-to-temperature" => &mut table.light_temperature,
+                "temperature-to-humidity" => &mut table.temperature_humidity,
+                "humidity-to-location" => &mut table.humidity_location,
+                _ => return Err(src.unexpected(name, "the name of a map, `seed-to-soil` to `humidity-to-location`").into()),
+            };
+
+            for translation in &group[1..] {
+                let tokens = translation.split_whitespace().collect::<Vec<_>>();
+                let [destination, source, len] = tokens[..] else {
+                    return Err(src.unexpected(translation, "a destination, source and length").into())
+                };
+                let (destination, source, len) = (src.number::<i64>(destination)?, src.number::<i64>(source)?, src.number::<i64>(len)?);
+
+                // Both ends of the range have to fit, so that every number in it can be shifted
+                let fits = len >= 0 && source.checked_add(len).is_some() && destination.checked_add(len).is_some();
+                let delta = destination.checked_sub(source).filter(|_| fits)
+                    .ok_or_else(|| src.unexpected(translation, "a range of 64 bit numbers"))?;
+
+                range_maps.push(RangeMap { range: source..source + len, delta });
+            }
+        }
+
+        Ok(table)
+    }
+
+    fn part1(table: &TranslationTable) -> Result<i64> {
+        let min_location = table.seeds.iter().map(|s | table.get_seed_location(*s)).min().ok_or("There are no seeds")?;
+
+        Ok(min_location)
+    }
+
+    fn part2(table: &TranslationTable) -> Result<i64> {
+        if !table.seeds.len().is_multiple_of(2) {
+            return Err("The seeds don't pair up into starts and lengths".into());
+        }
+
+        // Empty ranges hold no seeds, but their start would still be taken for a location
+        let seeds = table.seeds.chunks(2)
+            .map(|pair| pair[0].checked_add(pair[1]).map(|end| pair[0]..end).ok_or_else(|| format!("The seeds {} {} run past the largest number", pair[0], pair[1])))
+            .filter(|range| !range.as_ref().is_ok_and(Range::is_empty))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let min_location = table.get_range_locations(seeds).iter().map(|r| r.start).min().ok_or("There are no seeds")?;
+
+        Ok(min_location)
+    }
+}
+
+impl Print for Day5 {
+    fn print(table: &TranslationTable) -> String {
+        table.to_string()
+    }
+}
+
+impl fmt::Display for TranslationTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seeds = self.seeds.iter().map(i64::to_string).collect::<Vec<_>>();
+        write!(f, "seeds: {}", seeds.join(" "))?;
+
+        for (name, range_maps) in self.stages() {
+            write!(f, "\n\n{name} map:")?;
+            for range_map in range_maps {
+                write!(f, "\n{range_map}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Destination start, source start and length, as the almanac has them
+impl fmt::Display for RangeMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.range.start + self.delta, self.range.start, self.range.end - self.range.start)
+    }
+}
+
+/*--- Day 5: If You Give A Seed A Fertilizer ---
+You take the boat and find the gardener right where you were told he would be: managing a giant "garden" that looks more to you like a farm.
+
+"A water source? Island Island is the water source!" You point out that Snow Island isn't receiving any water.
+
+"Oh, we had to stop the water because we ran out of sand to filter it with! Can't make snow with dirty water. Don't worry, I'm sure we'll get more sand soon; we only turned off the water a few days... weeks... oh no." His face sinks into a look of horrified realization.
+
+"I've been so busy making sure everyone here has food that I completely forgot to check why we stopped getting more sand! There's a ferry leaving soon that is headed over in that direction - it's much faster than your boat. Could you please go check it out?"
+
+You barely have time to agree to this request when he brings up another. "While you wait for the ferry, maybe you can help us with our food production problem. The latest Island Island Almanac just arrived and we're having trouble making sense of it."
+
+The almanac (your puzzle input) lists all of the seeds that need to be planted. It also lists what type of soil to use with each kind of seed, what type of fertilizer to use with each kind of soil, what type of water to use with each kind of fertilizer, and so on. Every type of seed, soil, fertilizer and so on is identified with a number, but numbers are reused by each category - that is, soil 123 and fertilizer 123 aren't necessarily related to each other.
+
+For example:
+
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+The almanac starts by listing which seeds need to be planted: seeds 79, 14, 55, and 13.
+
+The rest of the almanac contains a list of maps which describe how to convert numbers from a source category into numbers in a destination category. That is, the section that starts with seed-to-soil map: describes how to convert a seed number (the source) to a soil number (the destination). This lets the gardener and his team know which soil to use with which seeds, which water to use with which fertilizer, and so on.
+
+Rather than list every source number and its corresponding destination number one by one, the maps describe entire ranges of numbers that can be converted. Each line within a map contains three numbers: the destination range start, the source range start, and the range length.
+
+Consider again the example seed-to-soil map:
+
+50 98 2
+52 50 48
+The first line has a destination range start of 50, a source range start of 98, and a range length of 2. This line means that the source range starts at 98 and contains two values: 98 and 99. The destination range is the same length, but it starts at 50, so its two values are 50 and 51. With this information, you know that seed number 98 corresponds to soil number 50 and that seed number 99 corresponds to soil number 51.
+
+The second line means that the source range starts at 50 and contains 48 values: 50, 51, ..., 96, 97. This corresponds to a destination range starting at 52 and also containing 48 values: 52, 53, ..., 98, 99. So, seed number 53 corresponds to soil number 55.
+
+Any source numbers that aren't mapped correspond to the same destination number. So, seed number 10 corresponds to soil number 10.
+
+So, the entire list of seed numbers and their corresponding soil numbers looks like this:
+
+seed  soil
+0     0
+1     1
+...   ...
+48    48
+49    49
+50    52
+51    53
+...   ...
+96    98
+97    99
+98    50
+99    51
+With this map, you can look up the soil number required for each initial seed number:
+
+Seed number 79 corresponds to soil number 81.
+Seed number 14 corresponds to soil number 14.
+Seed number 55 corresponds to soil number 57.
+Seed number 13 corresponds to soil number 13.
+The gardener and his team want to get started as soon as possible, so they'd like to know the closest location that needs a seed. Using these maps, find the lowest location number that corresponds to any of the initial seeds. To do this, you'll need to convert each seed number through other categories until you can find its corresponding location number. In this example, the corresponding types are:
+
+Seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78, location 82.
+Seed 14, soil 14, fertilizer 53, water 49, light 42, temperature 42, humidity 43, location 43.
+Seed 55, soil 57, fertilizer 57, water 53, light 46, temperature 82, humidity 82, location 86.
+Seed 13, soil 13, fertilizer 52, water 41, light 34, temperature 34, humidity 35, location 35.
+So, the lowest location number in this example is 35.
+
+What is the lowest location number that corresponds to any of the initial seed numbers?
+
+--- Part Two ---
+Everyone will starve if you only plant such a small number of seeds. Re-reading the almanac, it looks like the seeds: line actually describes ranges of seed numbers.
+
+The values on the initial seeds: line come in pairs. Within each pair, the first value is the start of the range and the second value is the length of the range. So, in the first line of the example above:
+
+seeds: 79 14 55 13
+This line describes two ranges of seed numbers to be planted in the garden. The first range starts with seed number 79 and contains 14 values: 79, 80, ..., 91, 92. The second range starts with seed number 55 and contains 13 values: 55, 56, ..., 66, 67.
+
+Now, rather than considering four seed numbers, you need to consider a total of 27 seed numbers.
+
+In the above example, the lowest location number can be obtained from seed number 82, which corresponds to soil 84, fertilizer 84, water 84, light 77, temperature 45, humidity 46, and location 46. So, the lowest location number is 46.
+
+Consider all of the initial seed numbers listed in the ranges on the first line of the almanac. What is the lowest location number that corresponds to any of the initial seed numbers? */
\ No newline at end of file
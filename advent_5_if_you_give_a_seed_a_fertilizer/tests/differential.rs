@@ -0,0 +1,23 @@
+use advent_5_if_you_give_a_seed_a_fertilizer::{generate::generate, Day5};
+use aoc_common::testing::{differential, part1, part2};
+
+// Every seed of the part 2 ranges spelled out, so part 1 looks each of them up one by one
+fn seeds_from_ranges(input: &str) -> String {
+    let (seeds, maps) = input.split_once('\n').unwrap();
+    let numbers = seeds.trim_start_matches("seeds:").split_whitespace().map(|n| n.parse::<i64>().unwrap()).collect::<Vec<_>>();
+    let seeds = numbers.chunks(2).flat_map(|pair| pair[0]..pair[0] + pair[1]).map(|s| s.to_string()).collect::<Vec<_>>();
+
+    format!("seeds: {}\n{maps}", seeds.join(" "))
+}
+
+#[test]
+fn range_splitting_matches_every_seed() {
+    differential(0..100, |seed| generate(seed, 1 + seed as usize % 8), part2::<Day5>, |input| part1::<Day5>(&seeds_from_ranges(input)));
+}
+
+#[test]
+fn empty_seed_ranges_hold_no_seeds() {
+    let input = "seeds: 5 0 10 3\n\nseed-to-soil map:\n100 0 4\n";
+    assert_eq!(part2::<Day5>(input), "10");
+    assert_eq!(part2::<Day5>(input), part1::<Day5>(&seeds_from_ranges(input)));
+}
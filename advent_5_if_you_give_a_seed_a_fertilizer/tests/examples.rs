@@ -0,0 +1,43 @@
+use advent_5_if_you_give_a_seed_a_fertilizer::Day5;
+use aoc_common::{testing::{parse, part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day5>(EXAMPLE), "35");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day5>(EXAMPLE), "46");
+}
+
+#[test]
+fn unknown_maps_are_an_error() {
+    let error = Day5::parse("seeds: 1 2\n\nseed-to-dirt map:\n1 2 3\n").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 3, column 1: expected the name of a map, `seed-to-soil` to `humidity-to-location`, found `seed-to-dirt`");
+}
+
+#[test]
+fn missing_seeds_are_an_error() {
+    let error = Day5::parse("seed-to-soil map:\n1 2 3\n").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 1, column 1: expected `seeds`, found `seed-to-soil map`");
+}
+
+#[test]
+fn overflowing_ranges_are_an_error() {
+    let error = Day5::parse("seeds: 1 2\n\nseed-to-soil map:\n0 9223372036854775807 2\n").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 4, column 1: expected a range of 64 bit numbers, found `0 9223372036854775807 2`");
+}
+
+#[test]
+fn no_seeds_are_an_error() {
+    let table = parse::<Day5>("seeds:\n\nseed-to-soil map:\n1 2 3\n");
+
+    assert_eq!(Day5::part1(&table).unwrap_err().to_string(), "There are no seeds");
+    assert_eq!(Day5::part2(&table).unwrap_err().to_string(), "There are no seeds");
+}
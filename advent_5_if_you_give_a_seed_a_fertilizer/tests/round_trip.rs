@@ -0,0 +1,7 @@
+use advent_5_if_you_give_a_seed_a_fertilizer::{generate::generate, Day5};
+use aoc_common::testing::round_trip;
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day5>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
@@ -0,0 +1,16 @@
+use aoc_common::Rng;
+
+// `size` races of up to 99ms, each record can be beaten by holding the button long enough
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    let races = (0..size).map(|_| {
+        let time = rng.range(3..=99);
+        let best = (time / 2) * (time - time / 2);
+        (time, rng.range(1..best))
+    }).collect::<Vec<_>>();
+
+    let times = races.iter().map(|(time, _)| format!("{time:>5}")).collect::<String>();
+    let distances = races.iter().map(|(_, distance)| format!("{distance:>5}")).collect::<String>();
+    format!("Time:    {times}\nDistance:{distances}\n")
+}
@@ -0,0 +1,171 @@
+pub mod generate;
+
+use std::fmt;
+
+use aoc_common::{trace, BigUint, ParseResult, Print, Result, Solution, Source};
+
+#[derive(PartialEq)]
+pub struct Leaderboard {
+    pub races: Vec<Race>
+}
+
+#[derive(PartialEq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Leaderboard;
+    type Answer1 = BigUint;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Leaderboard> {
+        let source = Source::new(input);
+        let input = input.lines().collect::<Vec<_>>();
+        let [times, distance] = input[..] else {
+            return Err("Expected a time and a distance line".into())
+        };
+
+        let numbers = |line| -> ParseResult<Vec<u64>> {
+            source.split_once(line, ":")?.1.split_whitespace().map(|n| source.number::<u64>(n)).collect()
+        };
+        let (times, distance_line) = (numbers(times)?, distance);
+        let distance = numbers(distance)?;
+        if distance.len() != times.len() {
+            return Err(source.unexpected(distance_line, &format!("a distance for each of the {} times", times.len())).into());
+        }
+
+        Ok(Leaderboard { races: times.iter().zip(distance).map(|x| Race { time: *x.0, distance: x.1}).collect::<Vec<_>>() })
+    }
+
+    // A long leaderboard multiplies past any machine integer
+    fn part1(leaderboard: &Leaderboard) -> Result<BigUint> {
+        let mut total_wins = Vec::new();
+
+        for race in &leaderboard.races {
+            let mut race_wins = 0;
+            let mut points_per_it = 0u128;
+
+            let mut time = race.time as u128;
+            while time > 0 {
+                let points = points_per_it * time;
+                if points > race.distance as u128 {
+                    race_wins += 1;
+                }
+
+                points_per_it += 1;
+                time -= 1;
+            }
+
+            trace!(trace::STEP, "race", "time": race.time, "distance": race.distance, "wins": race_wins);
+            total_wins.push(race_wins);
+        }
+
+        Ok(total_wins.into_iter().product())
+    }
+
+    fn part2(leaderboard: &Leaderboard) -> Result<u128> {
+        let joined = |number: fn(&Race) -> u64| leaderboard.races.iter().map(|race| number(race).to_string()).collect::<String>();
+        let (time, distance) = (joined(|race| race.time), joined(|race| race.distance));
+        let too_long = |what: &str, digits: &str| format!("The joined race {what} has {} digits, more than 64 bits hold", digits.len());
+        let time = time.parse::<u64>().map_err(|_| too_long("time", &time))?;
+        let distance = distance.parse::<u64>().map_err(|_| too_long("distance", &distance))?;
+
+        let wins = ways_to_win(time, distance);
+        trace!(trace::MILESTONE, "race", "time": time, "distance": distance, "wins": wins.to_string());
+        Ok(wins)
+    }
+}
+
+impl Print for Day6 {
+    fn print(leaderboard: &Leaderboard) -> String {
+        leaderboard.to_string()
+    }
+}
+
+// Times and distances in right aligned columns, one per race
+impl fmt::Display for Leaderboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.races.iter().map(|race| race.time.to_string().len().max(race.distance.to_string().len())).collect::<Vec<_>>();
+        let row = |number: fn(&Race) -> u64| self.races.iter().zip(&widths).map(|(race, width)| format!("  {:>width$}", number(race))).collect::<String>();
+
+        writeln!(f, "Time:    {}", row(|race| race.time))?;
+        write!(f, "Distance:{}", row(|race| race.distance))
+    }
+}
+
+// Beating the record means hold * (time - hold) >= distance + 1, which is (time - 2 hold)^2 <= time^2 - 4 (distance + 1).
+// In whole numbers, so nothing is rounded: time - 2 hold runs from -root to root, in steps of 2 from time
+fn ways_to_win(time: u64, distance: u64) -> u128 {
+    let (time, distance) = (time as u128, distance as u128);
+    let Some(room) = (time * time).checked_sub(4 * (distance + 1)) else {
+        return 0;
+    };
+
+    let root = room.isqrt();
+    if (time - root) % 2 == 0 {
+        root + 1
+    } else {
+        root
+    }
+}
+
+/* --- Day 6: Wait For It ---
+The ferry quickly brings you across Island Island. After asking around, you discover that there is indeed normally a large pile of sand somewhere near here, but you don't see anything besides lots of water and the small island where the ferry has docked.
+
+As you try to figure out what to do next, you notice a poster on a wall near the ferry dock. "Boat races! Open to the public! Grand prize is an all-expenses-paid trip to Desert Island!" That must be where the sand comes from! Best of all, the boat races are starting in just a few minutes.
+
+You manage to sign up as a competitor in the boat races just in time. The organizer explains that it's not really a traditional race - instead, you will get a fixed amount of time during which your boat has to travel as far as it can, and you win if your boat goes the farthest.
+
+As part of signing up, you get a sheet of paper (your puzzle input) that lists the time allowed for each race and also the best distance ever recorded in that race. To guarantee you win the grand prize, you need to make sure you go farther in each race than the current record holder.
+
+The organizer brings you over to the area where the boat races are held. The boats are much smaller than you expected - they're actually toy boats, each with a big button on top. Holding down the button charges the boat, and releasing the button allows the boat to move. Boats move faster if their button was held longer, but time spent holding the button counts against the total race time. You can only hold the button at the start of the race, and boats don't move until the button is released.
+
+For example:
+
+Time:      7  15   30
+Distance:  9  40  200
+This document describes three races:
+
+The first race lasts 7 milliseconds. The record distance in this race is 9 millimeters.
+The second race lasts 15 milliseconds. The record distance in this race is 40 millimeters.
+The third race lasts 30 milliseconds. The record distance in this race is 200 millimeters.
+Your toy boat has a starting speed of zero millimeters per millisecond. For each whole millisecond you spend at the beginning of the race holding down the button, the boat's speed increases by one millimeter per millisecond.
+
+So, because the first race lasts 7 milliseconds, you only have a few options:
+
+Don't hold the button at all (that is, hold it for 0 milliseconds) at the start of the race. The boat won't move; it will have traveled 0 millimeters by the end of the race.
+Hold the button for 1 millisecond at the start of the race. Then, the boat will travel at a speed of 1 millimeter per millisecond for 6 milliseconds, reaching a total distance traveled of 6 millimeters.
+Hold the button for 2 milliseconds, giving the boat a speed of 2 millimeters per millisecond. It will then get 5 milliseconds to move, reaching a total distance of 10 millimeters.
+Hold the button for 3 milliseconds. After its remaining 4 milliseconds of travel time, the boat will have gone 12 millimeters.
+Hold the button for 4 milliseconds. After its remaining 3 milliseconds of travel time, the boat will have gone 12 millimeters.
+Hold the button for 5 milliseconds, causing the boat to travel a total of 10 millimeters.
+Hold the button for 6 milliseconds, causing the boat to travel a total of 6 millimeters.
+Hold the button for 7 milliseconds. That's the entire duration of the race. You never let go of the button. The boat can't move until you let go of the button. Please make sure you let go of the button so the boat gets to move. 0 millimeters.
+Since the current record for this race is 9 millimeters, there are actually 4 different ways you could win: you could hold the button for 2, 3, 4, or 5 milliseconds at the start of the race.
+
+In the second race, you could hold the button for at least 4 milliseconds and at most 11 milliseconds and beat the record, a total of 8 different ways to win.
+
+In the third race, you could hold the button for at least 11 milliseconds and no more than 19 milliseconds and still beat the record, a total of 9 ways you could win.
+
+To see how much margin of error you have, determine the number of ways you can beat the record in each race; in this example, if you multiply these values together, you get 288 (4 * 8 * 9).
+
+Determine the number of ways you could beat the record in each race. What do you get if you multiply these numbers together? 
+
+--- Part Two ---
+As the race is about to start, you realize the piece of paper with race times and record distances you got earlier actually just has very bad kerning. There's really only one race - ignore the spaces between the numbers on each line.
+
+So, the example from before:
+
+Time:      7  15   30
+Distance:  9  40  200
+...now instead means this:
+
+Time:      71530
+Distance:  940200
+Now, you have to figure out how many ways there are to win this single race. In this example, the race lasts for 71530 milliseconds and the record distance you need to beat is 940200 millimeters. You could hold the button anywhere from 14 to 71516 milliseconds and beat the record, a total of 71503 ways!
+
+How many ways can you beat the record in this one much longer race? */
\ No newline at end of file
@@ -0,0 +1,22 @@
+use advent_6_wait_for_it::{generate::generate, Day6};
+use aoc_common::testing::{differential, part1, part2};
+
+// Every hold time tried one by one, on the race with the digits of all races joined
+fn brute_force(input: &str) -> String {
+    let joined = input.lines()
+        .map(|line| line.split_once(':').unwrap().1.split_whitespace().collect::<String>().parse::<u64>().unwrap())
+        .collect::<Vec<_>>();
+    let (time, distance) = (joined[0], joined[1]);
+
+    (0..=time).filter(|hold| hold * (time - hold) > distance).count().to_string()
+}
+
+#[test]
+fn closed_form_matches_loop_on_one_race() {
+    differential(0..200, |seed| generate(seed, 1), part2::<Day6>, part1::<Day6>);
+}
+
+#[test]
+fn closed_form_matches_brute_force() {
+    differential(0..200, |seed| generate(seed, 1 + seed as usize % 3), part2::<Day6>, brute_force);
+}
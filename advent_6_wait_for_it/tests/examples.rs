@@ -0,0 +1,37 @@
+use advent_6_wait_for_it::Day6;
+use aoc_common::{testing::{parse, part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day6>(EXAMPLE), "288");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day6>(EXAMPLE), "71503");
+}
+
+// 130 races won 2 ways each multiply to 2^130, past u128
+#[test]
+fn long_leaderboards_multiply_exactly() {
+    let input = format!("Time:{}\nDistance:{}\n", " 3".repeat(130), " 1".repeat(130));
+
+    assert_eq!(part1::<Day6>(&input), "1361129467683753853853498429727072845824");
+}
+
+#[test]
+fn joined_races_past_64_bits_are_an_error() {
+    let input = format!("Time:{}\nDistance:{}\n", " 99".repeat(10), " 1".repeat(10));
+    let error = Day6::part2(&parse::<Day6>(&input)).unwrap_err();
+
+    assert_eq!(error.to_string(), "The joined race time has 20 digits, more than 64 bits hold");
+}
+
+#[test]
+fn missing_distances_are_an_error() {
+    let error = Day6::parse("Time:      7  15   30\nDistance:  9  40\n").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 2, column 1: expected a distance for each of the 3 times, found `Distance:  9  40`");
+}
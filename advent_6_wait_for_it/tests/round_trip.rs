@@ -0,0 +1,7 @@
+use advent_6_wait_for_it::{generate::generate, Day6};
+use aoc_common::testing::round_trip;
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day6>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
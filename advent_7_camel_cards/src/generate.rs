@@ -0,0 +1,25 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+const LABELS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+// `size` distinct hands with bids up to 1000, labels drawn from a few cards at a time so every rank shows up
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut seen = HashSet::new();
+
+    let mut text = String::new();
+    while seen.len() < size.min(LABELS.len().pow(5)) {
+        let mut labels = LABELS.to_vec();
+        rng.shuffle(&mut labels);
+        labels.truncate(rng.range(1..=5) as usize);
+
+        let hand = (0..5).map(|_| *rng.choose(&labels)).collect::<String>();
+        if seen.insert(hand.clone()) {
+            text += &format!("{hand} {}\n", rng.range(1..=1000));
+        }
+    }
+
+    text
+}
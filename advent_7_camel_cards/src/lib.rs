@@ -0,0 +1,269 @@
+pub mod generate;
+
+use std::fmt;
+
+use aoc_common::{trace, ParseResult, Print, Result, Solution, Source};
+
+#[derive(PartialEq)]
+pub struct Table {
+    pub hands: Vec<Hand>
+}
+
+// Hands order by their rank, then card by card
+pub type Score = (Rank, Vec<Card>);
+
+#[derive(Clone, PartialEq)]
+pub struct Hand {
+    pub score: Score,
+    pub j_score: Score,
+    pub bid: usize,
+}
+
+impl Hand {
+    fn new(cards: Vec<Card>, bid: usize) -> Hand {
+        let score= Hand::get_score(&cards);
+        let j_score= Hand::get_j_score(&cards);
+
+        Hand { score, j_score, bid}
+    }
+
+    fn get_score(cards: &[Card]) -> Score {
+        (Hand::rank(cards), cards.to_vec())
+    }
+
+    fn get_j_score(cards: &[Card]) -> Score {
+        let cards = cards.iter().map(|c| if *c == Card::Jack { Card::Joker } else { *c }).collect::<Vec<_>>();
+
+        (Hand::rank(&cards), cards)
+    }
+
+    // By the two biggest groups of alike cards, jokers join whichever group is the biggest
+    fn rank(cards: &[Card]) -> Rank {
+        let mut counts = [0; 14];
+        for card in cards {
+            counts[*card as usize] += 1;
+        }
+
+        let jokers = std::mem::take(&mut counts[Card::Joker as usize]);
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+
+        match (counts[0] + jokers, counts[1]) {
+            (5.., _) => Rank::FiveOfAKind,
+            (4, _) => Rank::FourOfAKind,
+            (3, 2..) => Rank::FullHouse,
+            (3, _) => Rank::ThreeOfAKind,
+            (2, 2..) => Rank::TwoPair,
+            (2, _) => Rank::OnePair,
+            _ => Rank::HighCard,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rank {
+    FiveOfAKind = 7,
+    FourOfAKind = 6,
+    FullHouse = 5,
+    ThreeOfAKind = 4,
+    TwoPair = 3,
+    OnePair = 2,
+    HighCard = 1
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Card {
+    fn to_card(c: char) -> Option<Card> {
+        let card = match c {
+            '2' => Card::Two,
+            '3' => Card::Three,
+            '4' => Card::Four,
+            '5' => Card::Five,
+            '6' => Card::Six,
+            '7' => Card::Seven,
+            '8' => Card::Eight,
+            '9' => Card::Nine,
+            'T' => Card::Ten,
+            'J' => Card::Jack,
+            'Q' => Card::Queen,
+            'K' => Card::King,
+            'A' => Card::Ace,
+            _ => return None
+        };
+
+        Some(card)
+    }
+
+    // A joker is a jack read differently, so it has the jack's label
+    fn label(self) -> char {
+        match self {
+            Card::Joker | Card::Jack => 'J',
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        }
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards = self.score.1.iter().map(|card| card.label()).collect::<String>();
+        write!(f, "{cards} {}", self.bid)
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hands = self.hands.iter().map(Hand::to_string).collect::<Vec<_>>();
+        write!(f, "{}", hands.join("\n"))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Table;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Table> {
+        let source = Source::new(input);
+        let hands = input.lines()
+        .map(|x| {
+            let (cards, bid) = source.split_once(x, " ")?;
+            if cards.chars().count() != 5 {
+                return Err(source.unexpected(cards, "a hand of five cards"));
+            }
+
+            Ok(Hand::new(source.chars(cards, "a card label", Card::to_card)?, source.number(bid)?))
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+
+        Ok(Table { hands })
+    }
+
+    fn part1(table: &Table) -> Result<usize> {
+        total_winnings(table, |hand| &hand.score)
+    }
+
+    fn part2(table: &Table) -> Result<usize> {
+        total_winnings(table, |hand| &hand.j_score)
+    }
+}
+
+impl Print for Day7 {
+    fn print(table: &Table) -> String {
+        table.to_string()
+    }
+}
+
+// Every bid times the place of its hand, the weakest hand first
+fn total_winnings(table: &Table, score: impl Fn(&Hand) -> &Score) -> Result<usize> {
+    let mut sorted_hands = table.hands.iter().collect::<Vec<_>>();
+    sorted_hands.sort_by_key(|hand| score(hand));
+
+    let win_amount = sorted_hands.iter().enumerate()
+        .inspect(|(idx, hand)| trace!(trace::STEP, "place", "hand": hand.to_string(), "rank": format!("{:?}", score(hand).0), "place": idx + 1))
+        .try_fold(0usize, |total, (idx, hand)| total.checked_add(hand.bid.checked_mul(idx + 1)?))
+        .ok_or("The winnings add up past what a usize holds")?;
+
+    Ok(win_amount)
+}
+
+/* --- Day 7: Camel Cards ---
+Your all-expenses-paid trip turns out to be a one-way, five-minute ride in an airship. (At least it's a cool airship!) It drops you off at the edge of a vast desert and descends back to Island Island.
+
+"Did you bring the parts?"
+
+You turn around to see an Elf completely covered in white clothing, wearing goggles, and riding a large camel.
+
+"Did you bring the parts?" she asks again, louder this time. You aren't sure what parts she's looking for; you're here to figure out why the sand stopped.
+
+"The parts! For the sand, yes! Come with me; I will show you." She beckons you onto the camel.
+
+After riding a bit across the sands of Desert Island, you can see what look like very large rocks covering half of the horizon. The Elf explains that the rocks are all along the part of Desert Island that is directly above Island Island, making it hard to even get there. Normally, they use big machines to move the rocks and filter the sand, but the machines have broken down because Desert Island recently stopped receiving the parts they need to fix the machines.
+
+You've already assumed it'll be your job to figure out why the parts stopped when she asks if you can help. You agree automatically.
+
+Because the journey will take a few days, she offers to teach you the game of Camel Cards. Camel Cards is sort of similar to poker except it's designed to be easier to play while riding a camel.
+
+In Camel Cards, you get a list of hands, and your goal is to order them based on the strength of each hand. A hand consists of five cards labeled one of A, K, Q, J, T, 9, 8, 7, 6, 5, 4, 3, or 2. The relative strength of each card follows this order, where A is the highest and 2 is the lowest.
+
+Every hand is exactly one type. From strongest to weakest, they are:
+
+Five of a kind, where all five cards have the same label: AAAAA
+Four of a kind, where four cards have the same label and one card has a different label: AA8AA
+Full house, where three cards have the same label, and the remaining two cards share a different label: 23332
+Three of a kind, where three cards have the same label, and the remaining two cards are each different from any other card in the hand: TTT98
+Two pair, where two cards share one label, two other cards share a second label, and the remaining card has a third label: 23432
+One pair, where two cards share one label, and the other three cards have a different label from the pair and each other: A23A4
+High card, where all cards' labels are distinct: 23456
+Hands are primarily ordered based on type; for example, every full house is stronger than any three of a kind.
+
+If two hands have the same type, a second ordering rule takes effect. Start by comparing the first card in each hand. If these cards are different, the hand with the stronger first card is considered stronger. If the first card in each hand have the same label, however, then move on to considering the second card in each hand. If they differ, the hand with the higher second card wins; otherwise, continue with the third card in each hand, then the fourth, then the fifth.
+
+So, 33332 and 2AAAA are both four of a kind hands, but 33332 is stronger because its first card is stronger. Similarly, 77888 and 77788 are both a full house, but 77888 is stronger because its third card is stronger (and both hands have the same first and second card).
+
+To play Camel Cards, you are given a list of hands and their corresponding bid (your puzzle input). For example:
+
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+This example shows five hands; each hand is followed by its bid amount. Each hand wins an amount equal to its bid multiplied by its rank, where the weakest hand gets rank 1, the second-weakest hand gets rank 2, and so on up to the strongest hand. Because there are five hands in this example, the strongest hand will have rank 5 and its bid will be multiplied by 5.
+
+So, the first step is to put the hands in order of strength:
+
+32T3K is the only one pair and the other hands are all a stronger type, so it gets rank 1.
+KK677 and KTJJT are both two pair. Their first cards both have the same label, but the second card of KK677 is stronger (K vs T), so KTJJT gets rank 2 and KK677 gets rank 3.
+T55J5 and QQQJA are both three of a kind. QQQJA has a stronger first card, so it gets rank 5 and T55J5 gets rank 4.
+Now, you can determine the total winnings of this set of hands by adding up the result of multiplying each hand's bid with its rank (765 * 1 + 220 * 2 + 28 * 3 + 684 * 4 + 483 * 5). So the total winnings in this example are 6440.
+
+Find the rank of every hand in your set. What are the total winnings?
+
+--- Part Two ---
+To make things a little more interesting, the Elf introduces one additional rule. Now, J cards are jokers - wildcards that can act like whatever card would make the hand the strongest type possible.
+
+To balance this, J cards are now the weakest individual cards, weaker even than 2. The other cards stay in the same order: A, K, Q, T, 9, 8, 7, 6, 5, 4, 3, 2, J.
+
+J cards can pretend to be whatever card is best for the purpose of determining hand type; for example, QJJQ2 is now considered four of a kind. However, for the purpose of breaking ties between two hands of the same type, J is always treated as J, not the card it's pretending to be: JKKK2 is weaker than QQQQ2 because J is weaker than Q.
+
+Now, the above example goes very differently:
+
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+32T3K is still the only one pair; it doesn't contain any jokers, so its strength doesn't increase.
+KK677 is now the only two pair, making it the second-weakest hand.
+T55J5, KTJJT, and QQQJA are now all four of a kind! T55J5 gets rank 3, QQQJA gets rank 4, and KTJJT gets rank 5.
+With the new joker rule, the total winnings in this example are 5905.
+
+Using the new joker rule, find the rank of every hand in your set. What are the new total winnings?*/
\ No newline at end of file
@@ -0,0 +1,14 @@
+use advent_7_camel_cards::Day7;
+use aoc_common::testing::{part1, part2};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day7>(EXAMPLE), "6440");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day7>(EXAMPLE), "5905");
+}
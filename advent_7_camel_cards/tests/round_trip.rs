@@ -0,0 +1,7 @@
+use advent_7_camel_cards::{generate::generate, Day7};
+use aoc_common::testing::round_trip;
+
+#[test]
+fn printed_input_parses_back() {
+    round_trip::<Day7>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}
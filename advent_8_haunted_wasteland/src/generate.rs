@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use aoc_common::Rng;
+
+const PRIMES: [usize; 22] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79];
+
+// Three letter names only go so far, every step of every ghost is a node of its own
+const NODES: usize = 15000;
+
+// `size` left/right instructions, at most 1000. Like the real input every ghost walks a single loop
+// that passes its `Z` node after a prime number of instruction cycles, and the first ghost goes from
+// AAA to ZZZ. Up to six ghosts, fewer for long instructions
+pub fn generate(seed: u64, size: usize) -> String {
+    with_ghosts(seed, size, 6)
+}
+
+// Like `generate` with at most `ghosts` ghosts, AAA included
+pub fn with_ghosts(seed: u64, size: usize, ghosts: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let commands = (0..size.clamp(1, 1000)).map(|_| if rng.chance(0.5) { 'L' } else { 'R' }).collect::<Vec<_>>();
+
+    let largest = NODES / (ghosts.max(1) * commands.len());
+    let mut primes = PRIMES.into_iter().filter(|&p| p <= largest.max(2)).collect::<Vec<_>>();
+    rng.shuffle(&mut primes);
+    primes.truncate(ghosts.max(1));
+
+    let fillers = "BCDEFGHIJKLMNOPQRSTUVWXY".chars().collect::<Vec<_>>();
+    let mut names = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut nodes = Vec::new();
+    for (ghost, cycles) in primes.into_iter().enumerate() {
+        let steps = cycles * commands.len();
+
+        let mut path = vec![if ghost == 0 { "AAA".to_string() } else { name(&mut rng, &mut names, 'A') }];
+        for _ in 1..steps {
+            let last = *rng.choose(&fillers);
+            path.push(name(&mut rng, &mut names, last));
+        }
+        path.push(if ghost == 0 { "ZZZ".to_string() } else { name(&mut rng, &mut names, 'Z') });
+
+        // The wrong turn leads somewhere else on the path, the `Z` node turns like the start
+        for (step, node) in path.iter().enumerate() {
+            let next = &path[if step < steps { step + 1 } else { 1 }];
+            let other = rng.choose(&path);
+            let (left, right) = match commands[step % commands.len()] {
+                'L' => (next, other),
+                _ => (other, next),
+            };
+            nodes.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+
+    rng.shuffle(&mut nodes);
+    format!("{}\n\n{}\n", commands.iter().collect::<String>(), nodes.join("\n"))
+}
+
+// No structure at all: `size` instructions and up to `ghosts` ghosts on a handful of nodes whose turns go
+// anywhere. Few of these ghosts keep to the single loops `generate` makes, they test that part 2 notices
+pub fn tangled(seed: u64, size: usize, ghosts: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let commands = (0..size.clamp(1, 1000)).map(|_| if rng.chance(0.5) { 'L' } else { 'R' }).collect::<String>();
+
+    let fillers = "BCDEFGHIJKLMNOPQRSTUVWXY".chars().collect::<Vec<_>>();
+    let mut names = HashSet::new();
+    let mut path = Vec::new();
+    for _ in 0..ghosts.max(1) {
+        path.push(name(&mut rng, &mut names, 'A'));
+    }
+    for _ in 0..1 + rng.below(ghosts.max(1)) {
+        path.push(name(&mut rng, &mut names, 'Z'));
+    }
+    for _ in 0..rng.below(4) {
+        let last = *rng.choose(&fillers);
+        path.push(name(&mut rng, &mut names, last));
+    }
+
+    let nodes = path.iter()
+        .map(|node| format!("{node} = ({}, {})", rng.choose(&path), rng.choose(&path)))
+        .collect::<Vec<_>>();
+    format!("{commands}\n\n{}\n", nodes.join("\n"))
+}
+
+fn name(rng: &mut Rng, names: &mut HashSet<String>, last: char) -> String {
+    loop {
+        let name = format!("{}{}{last}", (b'A' + rng.below(26) as u8) as char, (b'A' + rng.below(26) as u8) as char);
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
@@ -0,0 +1,253 @@
+pub mod generate;
+
+use std::{collections::{HashMap, HashSet}, fmt};
+use aoc_common::{trace, BigUint, ParseResult, Print, Result, Solution, Source};
+
+#[derive(PartialEq)]
+pub struct Map {
+    pub commands: Vec<Command>,
+    pub locations: HashMap<String, Location>
+}
+
+impl Map {
+    // Steps from `from`, with the instructions starting at `at`, to the first node after it that `is_end`
+    // accepts, and that node. A walk longer than there are nodes at instructions goes round in circles
+    fn walk<'a>(&'a self, from: &'a str, at: usize, is_end: impl Fn(&str) -> bool) -> Option<(u64, &'a str)> {
+        let mut node = from;
+        for steps in 1..=self.locations.len() * self.commands.len() {
+            node = self.turn(node, at + steps - 1);
+            if is_end(node) {
+                return Some((steps as u64, node));
+            }
+        }
+
+        None
+    }
+
+    // Whether a ghost first on a `Z` node after `steps` steps, at `end`, is on one every `steps` steps and
+    // never in between. Walking on until it is back at `end` at the same instruction covers all it will do
+    fn ends_every(&self, end: &str, steps: u64) -> bool {
+        let at = (steps % self.commands.len() as u64) as usize;
+        let mut node = end;
+        for offset in 1..=self.locations.len() * self.commands.len() {
+            node = self.turn(node, at + offset - 1);
+            if node.ends_with('Z') != (offset as u64).is_multiple_of(steps) {
+                return false;
+            }
+            if node == end && offset.is_multiple_of(self.commands.len()) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    // The node the `command`th instruction leads to from `node`, the instructions repeating
+    fn turn(&self, node: &str, command: usize) -> &str {
+        let location = &self.locations[node];
+        match self.commands[command % self.commands.len()] {
+            Command::Left => &location.left,
+            Command::Right => &location.right,
+        }
+    }
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Location {
+    pub curr: String,
+    pub left: String,
+    pub right: String
+}
+
+impl Location {
+    fn new(curr: String, left: String, right: String) -> Location {
+        Location { curr, left, right }
+    }
+}
+
+#[derive(PartialEq)]
+pub enum Command {
+    Left,
+    Right
+}
+
+impl Command {
+    fn new(cmd: char) -> Option<Command> {
+        match cmd {
+            'L' => Some(Command::Left),
+            'R' => Some(Command::Right),
+            _ => None
+        }
+    }
+}
+
+// The instructions, then the nodes in name order
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let commands = self.commands.iter().map(|cmd| match cmd {
+            Command::Left => 'L',
+            Command::Right => 'R',
+        }).collect::<String>();
+        writeln!(f, "{commands}")?;
+
+        let mut locations = self.locations.values().collect::<Vec<_>>();
+        locations.sort();
+        for location in locations {
+            write!(f, "\n{location}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = ({}, {})", self.curr, self.left, self.right)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = BigUint;
+
+    fn parse(input: &str) -> Result<Map> {
+        let source = Source::new(input);
+        let (instructions, locations) = source.split_once(input, "\n\n")?;
+
+        let commands = source.chars(instructions, "`L` or `R`", Command::new)?;
+        if commands.is_empty() {
+            return Err(source.missing(instructions, "`L` or `R`").into());
+        }
+
+        let trims = ['(', ')', ' '];
+        let nodes = locations.lines()
+            .map(|s| {
+                let (c, l) = source.split_once(s, "=")?;
+                let (c, l) = (c.trim(), l.trim_matches(&trims[0..]));
+                let (left, right) = source.split_once(l, ",")?;
+
+                Ok((c, left.trim(), right.trim()))
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        // Every turn has to lead to a node of the map
+        let names = nodes.iter().map(|(name, _, _)| *name).collect::<HashSet<_>>();
+        if let Some(next) = nodes.iter().flat_map(|&(_, left, right)| [left, right]).find(|next| !names.contains(next)) {
+            return Err(source.unexpected(next, "a node of the map").into());
+        }
+
+        let locations = nodes.into_iter()
+            .map(|(c, left, right)| (String::from(c), Location::new(String::from(c), String::from(left), String::from(right))))
+            .collect();
+
+        Ok(Map { commands, locations })
+    }
+
+    fn part1(map: &Map) -> Result<u64> {
+        if !map.locations.contains_key("AAA") {
+            return Err("There is no node AAA to start from".into());
+        }
+
+        let (steps, _) = map.walk("AAA", 0, |node| node == "ZZZ").ok_or("ZZZ can't be reached from AAA")?;
+        trace!(trace::MILESTONE, "arrive", "from": "AAA", "to": "ZZZ", "steps": steps);
+        Ok(steps)
+    }
+
+    fn part2(map: &Map) -> Result<BigUint> {
+        let mut starts = map.locations.keys()
+            .filter(|l| l.ends_with('A'))
+            .collect::<Vec<_>>();
+        if starts.is_empty() {
+            return Err("There are no nodes ending in A to start from".into());
+        }
+        starts.sort();
+
+        let steps = starts.into_iter()
+            .map(|from| {
+                let (steps, end) = map.walk(from, 0, |node| node.ends_with('Z')).ok_or_else(|| format!("No node ending in Z can be reached from {from}"))?;
+
+                // The LCM of the first steps only meets the ghosts if each of them keeps to that beat
+                if !map.ends_every(end, steps) {
+                    return Err(format!("The ghost from {from} isn't on a node ending in Z every {steps} steps, so the LCM doesn't meet the ghosts"));
+                }
+                trace!(trace::MILESTONE, "arrive", "from": from, "to": end, "steps": steps);
+                Ok(steps)
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let ans = BigUint::lcm_of(&steps);
+        Ok(ans)
+    }
+}
+
+impl Print for Day8 {
+    fn print(map: &Map) -> String {
+        map.to_string()
+    }
+}
+
+/* --- Day 8: Haunted Wasteland ---
+You're still riding a camel across Desert Island when you spot a sandstorm quickly approaching. When you turn to warn the Elf, she disappears before your eyes! To be fair, she had just finished warning you about ghosts a few minutes ago.
+
+One of the camel's pouches is labeled "maps" - sure enough, it's full of documents (your puzzle input) about how to navigate the desert. At least, you're pretty sure that's what they are; one of the documents contains a list of left/right instructions, and the rest of the documents seem to describe some kind of network of labeled nodes.
+
+It seems like you're meant to use the left/right instructions to navigate the network. Perhaps if you have the camel follow the same instructions, you can escape the haunted wasteland!
+
+After examining the maps for a bit, two nodes stick out: AAA and ZZZ. You feel like AAA is where you are now, and you have to follow the left/right instructions until you reach ZZZ.
+
+This format defines each node of the network individually. For example:
+
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+Starting with AAA, you need to look up the next element based on the next left/right instruction in your input. In this example, start with AAA and go right (R) by choosing the right element of AAA, CCC. Then, L means to choose the left element of CCC, ZZZ. By following the left/right instructions, you reach ZZZ in 2 steps.
+
+Of course, you might not find ZZZ right away. If you run out of left/right instructions, repeat the whole sequence of instructions as necessary: RL really means RLRLRLRLRLRLRLRL... and so on. For example, here is a situation that takes 6 steps to reach ZZZ:
+
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+Starting at AAA, follow the left/right instructions. How many steps are required to reach ZZZ? 
+
+--- Part Two ---
+The sandstorm is upon you and you aren't any closer to escaping the wasteland. You had the camel follow the instructions, but you've barely left your starting position. It's going to take significantly more steps to escape!
+
+What if the map isn't for people - what if the map is for ghosts? Are ghosts even bound by the laws of spacetime? Only one way to find out.
+
+After examining the maps a bit longer, your attention is drawn to a curious fact: the number of nodes with names ending in A is equal to the number ending in Z! If you were a ghost, you'd probably just start at every node that ends with A and follow all of the paths at the same time until they all simultaneously end up at nodes that end with Z.
+
+For example:
+
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+Here, there are two starting nodes, 11A and 22A (because they both end with A). As you follow each left/right instruction, use that instruction to simultaneously navigate away from both nodes you're currently on. Repeat this process until all of the nodes you're currently on end with Z. (If only some of the nodes you're on end with Z, they act like any other node and you continue as normal.) In this example, you would proceed as follows:
+
+Step 0: You are at 11A and 22A.
+Step 1: You choose all of the left paths, leading you to 11B and 22B.
+Step 2: You choose all of the right paths, leading you to 11Z and 22C.
+Step 3: You choose all of the left paths, leading you to 11B and 22Z.
+Step 4: You choose all of the right paths, leading you to 11Z and 22B.
+Step 5: You choose all of the left paths, leading you to 11B and 22C.
+Step 6: You choose all of the right paths, leading you to 11Z and 22Z.
+So, in this example, you end up entirely on nodes that end in Z after 6 steps.
+
+Simultaneously start on every node that ends with A. How many steps does it take before you're only on nodes that end with Z?*/
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use advent_8_haunted_wasteland::{generate::{tangled, with_ghosts}, Day8};
+use aoc_common::{testing::{differential, parse, part2}, Solution};
+
+// All ghosts walk in lockstep until they stand on `Z` nodes at the same time, or until they have been
+// everywhere they can be together and never will
+fn brute_force(input: &str) -> Option<u64> {
+    let (commands, nodes) = input.split_once("\n\n").unwrap();
+    let nodes = nodes.lines()
+        .map(|line| (&line[0..3], (&line[7..10], &line[12..15])))
+        .collect::<HashMap<_, _>>();
+
+    let mut ghosts = nodes.keys().filter(|name| name.ends_with('A')).copied().collect::<Vec<_>>();
+    let states = nodes.len().pow(ghosts.len() as u32) * commands.len();
+    for (steps, command) in commands.chars().cycle().take(states).enumerate() {
+        if ghosts.iter().all(|ghost| ghost.ends_with('Z')) {
+            return Some(steps as u64);
+        }
+
+        for ghost in &mut ghosts {
+            *ghost = if command == 'L' { nodes[ghost].0 } else { nodes[ghost].1 };
+        }
+    }
+
+    None
+}
+
+#[test]
+fn lcm_matches_walking_in_lockstep() {
+    differential(0..30, |seed| with_ghosts(seed, 1 + seed as usize % 3, 2), part2::<Day8>, |input| brute_force(input).unwrap().to_string());
+}
+
+// Part 2 has to refuse maps the LCM doesn't fit, and whatever it answers has to be right
+#[test]
+fn lcm_is_right_or_refused_on_any_map() {
+    let mut answered = 0;
+    let mut wrong = Vec::new();
+    for seed in 0..300 {
+        let input = tangled(seed, 1 + seed as usize % 3, 1 + seed as usize % 3);
+        if let Ok(steps) = Day8::part2(&parse::<Day8>(&input)) {
+            answered += 1;
+            if brute_force(&input) != Some(steps.to_string().parse().unwrap()) {
+                wrong.push(seed);
+            }
+        }
+    }
+
+    assert!(wrong.is_empty(), "wrong answers for seeds {wrong:?}");
+    assert!((1..300).contains(&answered), "{answered} of 300 maps answered");
+}
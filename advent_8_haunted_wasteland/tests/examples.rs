@@ -0,0 +1,35 @@
+use advent_8_haunted_wasteland::Day8;
+use aoc_common::{testing::{parse, part1, part2}, Solution};
+
+const EXAMPLE_1: &str = include_str!("../fixtures/example_1.txt");
+const EXAMPLE_2: &str = include_str!("../fixtures/example_2.txt");
+const EXAMPLE_3: &str = include_str!("../fixtures/example_3.txt");
+
+#[test]
+fn part1_first_example() {
+    assert_eq!(part1::<Day8>(EXAMPLE_1), "2");
+}
+
+#[test]
+fn part1_repeating_instructions() {
+    assert_eq!(part1::<Day8>(EXAMPLE_2), "6");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day8>(EXAMPLE_3), "6");
+}
+
+#[test]
+fn turns_to_undefined_nodes_are_an_error() {
+    let error = Day8::parse("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 3, column 8: expected a node of the map, found `BBB`");
+}
+
+#[test]
+fn unreachable_zzz_is_an_error() {
+    let error = Day8::part1(&parse::<Day8>("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n")).unwrap_err();
+
+    assert_eq!(error.to_string(), "ZZZ can't be reached from AAA");
+}
@@ -0,0 +1,19 @@
+use aoc_common::Rng;
+
+const READINGS: i64 = 21;
+
+// `size` histories of 21 readings, each the values of a random polynomial of degree up to 4
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size).map(|_| {
+        let coefficients = (0..rng.range(1..=5)).map(|_| rng.range(0..=10) as i64 - 5).collect::<Vec<_>>();
+        let offset = rng.range(0..=10) as i64 - 5;
+
+        let values = (0..READINGS)
+            .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * (x + offset) + c))
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        values.join(" ") + "\n"
+    }).collect()
+}
@@ -0,0 +1,28 @@
+use advent_9_mirage_mainteance::Day9;
+use aoc_common::{testing::{part1, part2}, Solution};
+
+const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(part1::<Day9>(EXAMPLE), "114");
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(part2::<Day9>(EXAMPLE), "2");
+}
+
+#[test]
+fn empty_histories_are_an_error() {
+    let error = Day9::parse("0 3 6\n\n1 2").err().unwrap();
+
+    assert_eq!(error.to_string(), "line 2, column 1: expected a number, found end of line");
+}
+
+// Differences that sum to zero aren't all zeros yet
+#[test]
+fn short_histories_extrapolate() {
+    assert_eq!(part1::<Day9>("1 -1\n5\n1 3"), "7");
+    assert_eq!(part2::<Day9>("1 -1\n5\n1 3"), "7");
+}
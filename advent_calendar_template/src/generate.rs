@@ -0,0 +1,8 @@
+use aoc_common::Rng;
+
+// `size` lines of random numbers, replace with the shape of the puzzle input
+pub fn generate(seed: u64, size: usize) -> String {
+    let mut rng = Rng::new(seed);
+
+    (0..size).map(|_| format!("{}\n", rng.range(0..1000))).collect()
+}
@@ -0,0 +1,33 @@
+pub mod generate;
+
+use aoc_common::{Print, Result, Solution};
+
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<i32> {
+        for _line in lines {
+            
+        }
+
+        Ok(-1)
+    }
+
+    fn part2(_lines: &Vec<String>) -> Result<i32> {
+        Ok(-1)
+    }
+}
+
+impl Print for DayN {
+    fn print(lines: &Vec<String>) -> String {
+        lines.join("\n")
+    }
+}
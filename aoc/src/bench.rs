@@ -0,0 +1,95 @@
+use std::{fs, hint::black_box, path::Path, time::{Duration, Instant, SystemTime, UNIX_EPOCH}};
+
+use aoc_common::{solution, Result, Solution};
+use serde::{Serialize, Serializer};
+
+pub type BenchFn = fn(&str, &[u32], usize) -> Result<Timings>;
+
+#[derive(Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "max_ns", serialize_with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats { min: samples[0], median: samples[samples.len() / 2], max: samples[samples.len() - 1] }
+    }
+}
+
+// Parsing and each part are timed on their own, parts reuse a single parsed input
+#[derive(Serialize)]
+pub struct Timings {
+    pub parse: Stats,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Stats>,
+}
+
+impl Timings {
+    pub fn phases(&self) -> Vec<(&'static str, &Stats)> {
+        [("parse", Some(&self.parse)), ("part 1", self.part1.as_ref()), ("part 2", self.part2.as_ref())]
+            .into_iter()
+            .filter_map(|(phase, stats)| Some((phase, stats?)))
+            .collect()
+    }
+}
+
+#[derive(Serialize)]
+pub struct DayReport {
+    pub day: u32,
+    pub title: &'static str,
+    pub input: String,
+    #[serde(flatten)]
+    pub timings: Timings,
+}
+
+#[derive(Serialize)]
+pub struct Report {
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn new(iterations: usize) -> Report {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        Report { timestamp, iterations, days: Vec::new() }
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+}
+
+pub fn measure<S: Solution>(input: &str, parts: &[u32], iterations: usize) -> Result<Timings> {
+    let parse = time(iterations, || solution::parse::<S>(input))?;
+    let parsed = solution::parse::<S>(input)?;
+
+    let part1 = parts.contains(&1).then(|| time(iterations, || S::part1(&parsed))).transpose()?;
+    let part2 = parts.contains(&2).then(|| time(iterations, || S::part2(&parsed))).transpose()?;
+
+    Ok(Timings { parse, part1, part2 })
+}
+
+fn time<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(f()?);
+        samples.push(start.elapsed());
+    }
+
+    Ok(Stats::from_samples(samples))
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
@@ -0,0 +1,174 @@
+// Stand-in for adventofcode.com serving the input download and answer submission endpoints from fixture
+// files, so fetching and submitting can be tried and tested offline. For day N it serves `dayN.txt` and
+// judges answers against `dayN.answers.json`, a ledger like the runner keeps next to every input
+
+use std::{collections::HashMap, fs, io::{self, Write}, path::PathBuf, sync::Mutex, time::{Duration, Instant}};
+
+use clap::Parser;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const YEAR: u32 = 2023;
+
+#[derive(Parser)]
+#[command(name = "aoc_mock", about = "Local stand-in for the Advent of Code input and answer endpoints")]
+struct Args {
+    /// Directory with dayN.txt inputs and dayN.answers.json answers
+    #[arg(long)]
+    fixtures: PathBuf,
+
+    /// Port to listen on, 0 picks a free one
+    #[arg(long, default_value_t = 8023)]
+    port: u16,
+
+    /// Session cookie the server accepts
+    #[arg(long, default_value = "mock-session")]
+    session: String,
+
+    /// Seconds a part stays locked after a wrong answer
+    #[arg(long, default_value_t = 60)]
+    cooldown: u64,
+}
+
+#[derive(Default)]
+struct Progress {
+    solved: [bool; 2],
+    locked_until: Option<Instant>,
+}
+
+fn main() -> io::Result<()> {
+    let args = Args::parse();
+    let server = Server::http(("127.0.0.1", args.port)).map_err(io::Error::other)?;
+    let port = server.server_addr().to_ip().map_or(args.port, |addr| addr.port());
+
+    // Tests read the port off this line
+    println!("Listening on http://127.0.0.1:{port}");
+    io::stdout().flush()?;
+
+    let progress = Mutex::new(HashMap::new());
+    for request in server.incoming_requests() {
+        // A client hanging up early is no reason to stop serving
+        if let Err(e) = respond(&args, &progress, request) {
+            eprintln!("{e}");
+        }
+    }
+
+    Ok(())
+}
+
+fn respond(args: &Args, progress: &Mutex<HashMap<u32, Progress>>, mut request: Request) -> io::Result<()> {
+    let path = request.url().split('?').next().unwrap_or_default().to_string();
+    let day = path.strip_prefix(&format!("/{YEAR}/day/")).and_then(|rest| rest.split_once('/'));
+    let Some((Ok(day), endpoint)) = day.map(|(day, endpoint)| (day.parse::<u32>(), endpoint)) else {
+        return request.respond(text(404, "404 Not Found"));
+    };
+
+    if !has_session(&request, &args.session) {
+        return request.respond(text(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input."));
+    }
+
+    match (request.method(), endpoint) {
+        (Method::Get, "input") => match fs::read_to_string(args.fixtures.join(format!("day{day}.txt"))) {
+            Ok(input) => request.respond(Response::from_string(input)),
+            Err(_) => request.respond(text(404, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.")),
+        },
+        (Method::Post, "answer") => {
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body)?;
+            let form = parse_form(&body);
+            let (Some(level), Some(answer)) = (form.get("level").and_then(|l| l.parse::<usize>().ok()), form.get("answer")) else {
+                return request.respond(text(400, "Expected a level and an answer"));
+            };
+
+            let Some(expected) = expected_answer(args, day, level) else {
+                return request.respond(text(404, "404 Not Found"));
+            };
+
+            let mut progress = progress.lock().expect("responses don't panic");
+            let article = judge(progress.entry(day).or_default(), level, answer, &expected, Duration::from_secs(args.cooldown), day);
+            request.respond(page(&article))
+        },
+        _ => request.respond(text(404, "404 Not Found")),
+    }
+}
+
+// Same wording as the real site, which is what the client goes by
+fn judge(progress: &mut Progress, level: usize, answer: &str, expected: &str, cooldown: Duration, day: u32) -> String {
+    let back = format!("<a href=\"/{YEAR}/day/{day}\">[Return to Day {day}]</a>");
+    let next_level = progress.solved.iter().position(|solved| !solved).map(|part| part + 1);
+    if next_level != Some(level) {
+        return format!("You don't seem to be solving the right level.  Did you already complete it? {back}");
+    }
+
+    if let Some(left) = progress.locked_until.and_then(|until| until.checked_duration_since(Instant::now())) {
+        let seconds = left.as_secs() + 1;
+        let wait = if seconds >= 60 { format!("{}m {}s", seconds / 60, seconds % 60) } else { format!("{seconds}s") };
+        return format!("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {wait} left to wait. {back}");
+    }
+
+    if answer.trim() == expected {
+        progress.solved[level - 1] = true;
+        progress.locked_until = None;
+        return format!("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to restoring snow operations. {back}");
+    }
+
+    progress.locked_until = Some(Instant::now() + cooldown);
+    let hint = match (answer.trim().parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+        (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+        _ => "",
+    };
+    format!("That's not the right answer{hint}.  If you're stuck, make sure you're using the full input data. {back}")
+}
+
+fn expected_answer(args: &Args, day: u32, level: usize) -> Option<String> {
+    let ledger = fs::read_to_string(args.fixtures.join(format!("day{day}.answers.json"))).ok()?;
+    let ledger: serde_json::Value = serde_json::from_str(&ledger).ok()?;
+    ledger.get(format!("part{level}"))?.as_str().map(str::to_string)
+}
+
+fn has_session(request: &Request, session: &str) -> bool {
+    request.headers().iter()
+        .filter(|header| header.field.equiv("Cookie"))
+        .flat_map(|header| header.value.as_str().split(';'))
+        .any(|cookie| cookie.trim().strip_prefix("session=") == Some(session))
+}
+
+// `level=1&answer=142`, with `+` and `%XX` escapes
+fn parse_form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (decode(key), decode(value)))
+        .collect()
+}
+
+fn decode(text: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' if tail.len() >= 2 => match u8::from_str_radix(std::str::from_utf8(&tail[..2]).unwrap_or("??"), 16) {
+                Ok(decoded) => {
+                    bytes.push(decoded);
+                    rest = &tail[2..];
+                    continue;
+                },
+                Err(_) => bytes.push(byte),
+            },
+            byte => bytes.push(byte),
+        }
+        rest = tail;
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn text(status: u16, body: &str) -> Response<io::Cursor<Vec<u8>>> {
+    Response::from_string(format!("{body}\n")).with_status_code(status)
+}
+
+fn page(article: &str) -> Response<io::Cursor<Vec<u8>>> {
+    let html = format!("<!DOCTYPE html>\n<html lang=\"en-us\">\n<head><title>Day - Advent of Code {YEAR}</title></head>\n<body>\n<main>\n<article><p>{article}</p></article>\n</main>\n</body>\n</html>\n");
+    let content_type = Header::from_bytes("Content-Type", "text/html; charset=utf-8").expect("a valid header");
+    Response::from_string(html).with_header(content_type)
+}
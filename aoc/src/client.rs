@@ -0,0 +1,170 @@
+use std::{env, time::Duration};
+
+use aoc_common::Result;
+
+pub const YEAR: u32 = 2023;
+pub const SERVER: &str = "https://adventofcode.com";
+
+// The site asks tools to say who they are
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"), " (Advent of Code 2023 solutions)");
+
+// What the site said about a submitted answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // Wrong answers lock the part for a while, `wait` as the site words it, e.g. `4m 32s`
+    RateLimited { wait: Option<String> },
+    // The part was solved already, or part 1 isn't yet
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn describe(&self) -> String {
+        match self {
+            Verdict::Correct => "That's the right answer!".to_string(),
+            Verdict::Wrong => "That's not the right answer".to_string(),
+            Verdict::TooHigh => "That's not the right answer, it is too high".to_string(),
+            Verdict::TooLow => "That's not the right answer, it is too low".to_string(),
+            Verdict::RateLimited { wait: Some(wait) } => format!("Answered too recently, wait {wait} before trying again"),
+            Verdict::RateLimited { wait: None } => "Answered too recently, wait a bit before trying again".to_string(),
+            Verdict::WrongLevel => "Not the part to solve now, is it solved already?".to_string(),
+        }
+    }
+}
+
+// Input download and answer submission against adventofcode.com, or anything speaking the same
+// endpoints such as `aoc_mock`
+pub struct Client {
+    server: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(server: &str, session: &str) -> Client {
+        let agent = ureq::AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build();
+        Client { server: server.trim_end_matches('/').to_string(), session: session.trim().to_string(), agent }
+    }
+
+    // `AOC_SERVER` points at another server, `AOC_SESSION` holds the session cookie of the logged in browser
+    pub fn from_env(session: Option<&str>) -> Result<Client> {
+        let server = env::var("AOC_SERVER").unwrap_or_else(|_| SERVER.to_string());
+        let session = match session {
+            Some(session) => session.to_string(),
+            None => env::var("AOC_SESSION").map_err(|_| "No session cookie, set AOC_SESSION or pass --session")?,
+        };
+
+        Ok(Client::new(&server, &session))
+    }
+
+    pub fn input(&self, day: u32) -> Result<String> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.server);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call();
+
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(404, _)) => Err(format!("Day {day} has no input yet, it unlocks at midnight EST").into()),
+            Err(ureq::Error::Status(400 | 500, _)) => Err("The session cookie was rejected, log in again and copy a fresh one".into()),
+            Err(ureq::Error::Status(code, response)) => Err(format!("{url} answered {code} {}", response.status_text()).into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn submit(&self, day: u32, part: u32, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.server);
+        let level = part.to_string();
+        let response = self.agent.post(&url).set("Cookie", &self.cookie()).send_form(&[("level", &level), ("answer", answer)]);
+
+        match response {
+            Ok(response) => parse_verdict(&response.into_string()?),
+            Err(ureq::Error::Status(400 | 500, _)) => Err("The session cookie was rejected, log in again and copy a fresh one".into()),
+            Err(ureq::Error::Status(code, response)) => Err(format!("{url} answered {code} {}", response.status_text()).into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+// The answer page is HTML, the verdict is the text of its one `<article>`
+pub fn parse_verdict(html: &str) -> Result<Verdict> {
+    let article = html.split_once("<article>").and_then(|(_, rest)| rest.split_once("</article>")).map_or(html, |(article, _)| article);
+    let text = strip_tags(article);
+
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text.split_once("You have ").and_then(|(_, rest)| rest.split_once(" left to wait")).map(|(wait, _)| wait.to_string());
+        Verdict::RateLimited { wait }
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return Err(format!("Unexpected answer page: {}", text.trim()).into());
+    };
+
+    Ok(verdict)
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_verdict, Verdict};
+
+    fn page(article: &str) -> String {
+        format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{article}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn reads_every_verdict() {
+        let cases = [
+            ("That's the right answer!  You are <em>one gold star</em> closer to restoring snow operations.", Verdict::Correct),
+            ("That's not the right answer.  If you're stuck, make sure you're using the full input data.", Verdict::Wrong),
+            ("That's not the right answer; your answer is too high.  If you're stuck, ...", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.  If you're stuck, ...", Verdict::TooLow),
+            ("You don't seem to be solving the right level.  Did you already complete it?", Verdict::WrongLevel),
+        ];
+
+        for (article, verdict) in cases {
+            assert_eq!(parse_verdict(&page(article)).unwrap(), verdict, "{article}");
+        }
+    }
+
+    #[test]
+    fn reads_the_wait_of_a_rate_limit() {
+        let article = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a>";
+
+        assert_eq!(parse_verdict(&page(article)).unwrap(), Verdict::RateLimited { wait: Some("4m 32s".to_string()) });
+    }
+
+    #[test]
+    fn rejects_other_pages() {
+        assert!(parse_verdict("<html>Log in</html>").is_err());
+    }
+}
@@ -0,0 +1,362 @@
+use aoc_common::{params::Override, render::{Frame, Simulate, Stepper, Visualise}, solution, Answer, Configurable, Params, Result, Solution};
+
+use crate::bench::{self, BenchFn};
+
+pub type ParseFn = fn(&str, &[Override]) -> Result<Box<dyn Parsed>>;
+pub type GenerateFn = fn(u64, usize) -> String;
+pub type ShowFn = fn(&str) -> Result<Vec<Frame>>;
+pub type StepFn = fn(&str) -> Result<Box<dyn Stepper>>;
+
+pub struct Day {
+    pub day: u32,
+    pub title: &'static str,
+    pub dir: &'static str,
+    pub parse: ParseFn,
+    pub bench: BenchFn,
+    pub generate: GenerateFn,
+    // Only days that can draw their state
+    pub show: Option<ShowFn>,
+    // Only days with a simulation to step through
+    pub step: Option<StepFn>,
+}
+
+// An input parsed once, along with the parameters it is solved with, for both parts to share
+pub trait Parsed: Send + Sync {
+    fn part(&self, part: u32) -> Result<Answer>;
+}
+
+struct Plain<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Plain<S> where S::Input: Send + Sync {
+    fn part(&self, part: u32) -> Result<Answer> {
+        match part {
+            1 => Ok(S::part1(&self.0)?.into()),
+            _ => Ok(S::part2(&self.0)?.into()),
+        }
+    }
+}
+
+struct WithParams<S: Configurable>(S::Input, S::Params);
+
+impl<S: Configurable> Parsed for WithParams<S> where S::Input: Send + Sync, S::Params: Send + Sync {
+    fn part(&self, part: u32) -> Result<Answer> {
+        match part {
+            1 => Ok(S::part1_with(&self.0, &self.1)?.into()),
+            _ => Ok(S::part2_with(&self.0, &self.1)?.into()),
+        }
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str, overrides: &[Override]) -> Result<Box<dyn Parsed>> where S::Input: Send + Sync + 'static {
+    no_params(overrides)?;
+    Ok(Box::new(Plain::<S>(solution::parse::<S>(input)?)))
+}
+
+// Parameters are checked before parsing, a typo shouldn't wait for a slow parse
+fn parse_with<S: Configurable + 'static>(input: &str, overrides: &[Override]) -> Result<Box<dyn Parsed>> where S::Input: Send + Sync + 'static, S::Params: Send + Sync + 'static {
+    let params = S::Params::with(overrides)?;
+    Ok(Box::new(WithParams::<S>(solution::parse::<S>(input)?, params)))
+}
+
+fn no_params(overrides: &[Override]) -> Result<()> {
+    match overrides.first() {
+        Some(o) => Err(format!("No parameter `{}`, this day has none", o.name).into()),
+        None => Ok(()),
+    }
+}
+
+fn frames<S: Visualise>(input: &str) -> Result<Vec<Frame>> {
+    Ok(S::frames(&solution::parse::<S>(input)?))
+}
+
+fn stepper<S: Simulate>(input: &str) -> Result<Box<dyn Stepper>> {
+    Ok(S::stepper(solution::parse::<S>(input)?))
+}
+
+pub fn get(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        title: "Trebuchet?!",
+        dir: "advent_1_trebuchet",
+        parse: parse::<advent_1_trebuchet::Day1>,
+        bench: bench::measure::<advent_1_trebuchet::Day1>,
+        generate: advent_1_trebuchet::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 2,
+        title: "Cube Conundrum",
+        dir: "advent_2_cube_conundrum",
+        parse: parse_with::<advent_2_cube_conundrum::Day2>,
+        bench: bench::measure::<advent_2_cube_conundrum::Day2>,
+        generate: advent_2_cube_conundrum::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 3,
+        title: "Gear Ratios",
+        dir: "advent_3_gear_ratios",
+        parse: parse::<advent_3_gear_ratios::Day3>,
+        bench: bench::measure::<advent_3_gear_ratios::Day3>,
+        generate: advent_3_gear_ratios::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 4,
+        title: "Scratchcards",
+        dir: "advent_4_scratchcards",
+        parse: parse::<advent_4_scratchcards::Day4>,
+        bench: bench::measure::<advent_4_scratchcards::Day4>,
+        generate: advent_4_scratchcards::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 5,
+        title: "If You Give A Seed A Fertilizer",
+        dir: "advent_5_if_you_give_a_seed_a_fertilizer",
+        parse: parse::<advent_5_if_you_give_a_seed_a_fertilizer::Day5>,
+        bench: bench::measure::<advent_5_if_you_give_a_seed_a_fertilizer::Day5>,
+        generate: advent_5_if_you_give_a_seed_a_fertilizer::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 6,
+        title: "Wait For It",
+        dir: "advent_6_wait_for_it",
+        parse: parse::<advent_6_wait_for_it::Day6>,
+        bench: bench::measure::<advent_6_wait_for_it::Day6>,
+        generate: advent_6_wait_for_it::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 7,
+        title: "Camel Cards",
+        dir: "advent_7_camel_cards",
+        parse: parse::<advent_7_camel_cards::Day7>,
+        bench: bench::measure::<advent_7_camel_cards::Day7>,
+        generate: advent_7_camel_cards::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 8,
+        title: "Haunted Wasteland",
+        dir: "advent_8_haunted_wasteland",
+        parse: parse::<advent_8_haunted_wasteland::Day8>,
+        bench: bench::measure::<advent_8_haunted_wasteland::Day8>,
+        generate: advent_8_haunted_wasteland::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 9,
+        title: "Mirage Maintenance",
+        dir: "advent_9_mirage_mainteance",
+        parse: parse::<advent_9_mirage_mainteance::Day9>,
+        bench: bench::measure::<advent_9_mirage_mainteance::Day9>,
+        generate: advent_9_mirage_mainteance::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 10,
+        title: "Pipe Maze",
+        dir: "advent_10_pipe_maze",
+        parse: parse::<advent_10_pipe_maze::Day10>,
+        bench: bench::measure::<advent_10_pipe_maze::Day10>,
+        generate: advent_10_pipe_maze::generate::generate,
+        show: Some(frames::<advent_10_pipe_maze::Day10>),
+        step: None,
+    },
+    Day {
+        day: 11,
+        title: "Cosmic Expansion",
+        dir: "advent_11_cosmic_expansion",
+        parse: parse_with::<advent_11_cosmic_expansion::Day11>,
+        bench: bench::measure::<advent_11_cosmic_expansion::Day11>,
+        generate: advent_11_cosmic_expansion::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 12,
+        title: "Hot Springs",
+        dir: "advent_12_hot_springs",
+        parse: parse_with::<advent_12_hot_springs::Day12>,
+        bench: bench::measure::<advent_12_hot_springs::Day12>,
+        generate: advent_12_hot_springs::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 13,
+        title: "Point of Incidence",
+        dir: "advent_13_point_of_incidence",
+        parse: parse::<advent_13_point_of_incidence::Day13>,
+        bench: bench::measure::<advent_13_point_of_incidence::Day13>,
+        generate: advent_13_point_of_incidence::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 14,
+        title: "Parabolic Reflector Dish",
+        dir: "advent_14_parabolic_reflector_dish",
+        parse: parse_with::<advent_14_parabolic_reflector_dish::Day14>,
+        bench: bench::measure::<advent_14_parabolic_reflector_dish::Day14>,
+        generate: advent_14_parabolic_reflector_dish::generate::generate,
+        show: Some(frames::<advent_14_parabolic_reflector_dish::Day14>),
+        step: Some(stepper::<advent_14_parabolic_reflector_dish::Day14>),
+    },
+    Day {
+        day: 15,
+        title: "Lens Library",
+        dir: "advent_15_lens_library",
+        parse: parse_with::<advent_15_lens_library::Day15>,
+        bench: bench::measure::<advent_15_lens_library::Day15>,
+        generate: advent_15_lens_library::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 16,
+        title: "The Floor Will Be Lava",
+        dir: "advent_16_the_floor_will_be_lava",
+        parse: parse::<advent_16_the_floor_will_be_lava::Day16>,
+        bench: bench::measure::<advent_16_the_floor_will_be_lava::Day16>,
+        generate: advent_16_the_floor_will_be_lava::generate::generate,
+        show: Some(frames::<advent_16_the_floor_will_be_lava::Day16>),
+        step: Some(stepper::<advent_16_the_floor_will_be_lava::Day16>),
+    },
+    Day {
+        day: 17,
+        title: "Clumsy Crucible",
+        dir: "advent_17_clumsy_crucible",
+        parse: parse_with::<advent_17_clumsy_crucible::Day17>,
+        bench: bench::measure::<advent_17_clumsy_crucible::Day17>,
+        generate: advent_17_clumsy_crucible::generate::generate,
+        show: Some(frames::<advent_17_clumsy_crucible::Day17>),
+        step: None,
+    },
+    Day {
+        day: 18,
+        title: "Lavaduct Lagoon",
+        dir: "advent_18_lavaduct_lagoon",
+        parse: parse::<advent_18_lavaduct_lagoon::Day18>,
+        bench: bench::measure::<advent_18_lavaduct_lagoon::Day18>,
+        generate: advent_18_lavaduct_lagoon::generate::generate,
+        show: Some(frames::<advent_18_lavaduct_lagoon::Day18>),
+        step: None,
+    },
+    Day {
+        day: 19,
+        title: "Aplenty",
+        dir: "advent_19_aplenty",
+        parse: parse::<advent_19_aplenty::Day19>,
+        bench: bench::measure::<advent_19_aplenty::Day19>,
+        generate: advent_19_aplenty::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 20,
+        title: "Pulse Propagation",
+        dir: "advent_20_pulse_propagation",
+        parse: parse_with::<advent_20_pulse_propagation::Day20>,
+        bench: bench::measure::<advent_20_pulse_propagation::Day20>,
+        generate: advent_20_pulse_propagation::generate::generate,
+        show: None,
+        step: Some(stepper::<advent_20_pulse_propagation::Day20>),
+    },
+    Day {
+        day: 21,
+        title: "Step Counter",
+        dir: "advent_21_step_counter",
+        parse: parse_with::<advent_21_step_counter::Day21>,
+        bench: bench::measure::<advent_21_step_counter::Day21>,
+        generate: advent_21_step_counter::generate::generate,
+        show: Some(frames::<advent_21_step_counter::Day21>),
+        step: Some(stepper::<advent_21_step_counter::Day21>),
+    },
+    Day {
+        day: 22,
+        title: "Sand Slabs",
+        dir: "advent_22_sand_slabs",
+        parse: parse::<advent_22_sand_slabs::Day22>,
+        bench: bench::measure::<advent_22_sand_slabs::Day22>,
+        generate: advent_22_sand_slabs::generate::generate,
+        show: None,
+        step: None,
+    },
+    Day {
+        day: 23,
+        title: "A Long Walk",
+        dir: "advent_23_a_long_walk",
+        parse: parse::<advent_23_a_long_walk::Day23>,
+        bench: bench::measure::<advent_23_a_long_walk::Day23>,
+        generate: advent_23_a_long_walk::generate::generate,
+        show: None,
+        step: None,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{get, DAYS};
+
+    // Every generator has to come up with input its own day solves, the same for the same seed
+    #[test]
+    fn generated_inputs_solve() {
+        for day in DAYS {
+            for seed in 0..3 {
+                let input = (day.generate)(seed, 5);
+                assert_eq!(input, (day.generate)(seed, 5), "day {} input differs for seed {seed}", day.day);
+
+                let parsed = (day.parse)(&input, &[]).unwrap_or_else(|e| panic!("day {} failed to parse seed {seed}: {e}\n{input}", day.day));
+                for part in 1..=2 {
+                    if let Err(e) = parsed.part(part) {
+                        panic!("day {} part {part} failed for seed {seed}: {e}\n{input}", day.day);
+                    }
+                }
+
+                if let Some(show) = day.show {
+                    let frames = show(&input).unwrap_or_else(|e| panic!("day {} failed to draw seed {seed}: {e}", day.day));
+                    assert!(!frames.is_empty() && frames.iter().all(|f| f.cells.rows() > 0), "day {} drew nothing for seed {seed}", day.day);
+                }
+
+                if let Some(step) = day.step {
+                    let mut stepper = step(&input).unwrap_or_else(|e| panic!("day {} failed to step seed {seed}: {e}", day.day));
+                    for _ in 0..10 {
+                        stepper.step();
+                        assert!(stepper.frame().cells.rows() > 0, "day {} drew nothing stepping seed {seed}", day.day);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn params_reach_the_day() {
+        let input = (get(11).unwrap().generate)(1, 20);
+        let solve = |day: u32, part: u32, params: &[&str]| {
+            (get(day).unwrap().parse)(&input, &params.iter().map(|p| p.parse().unwrap()).collect::<Vec<_>>()).and_then(|parsed| parsed.part(part))
+        };
+
+        assert_eq!(solve(11, 2, &["expansion_pt2=2"]).unwrap(), solve(11, 1, &[]).unwrap());
+        assert_ne!(solve(11, 2, &["expansion_pt2=2"]).unwrap(), solve(11, 2, &[]).unwrap());
+        assert_eq!(solve(11, 2, &["expansion=2"]).unwrap_err().to_string(), "No parameter `expansion`, this day has expansion_pt1, expansion_pt2");
+        assert_eq!(solve(3, 1, &["x=1"]).unwrap_err().to_string(), "No parameter `x`, this day has none");
+        assert_eq!(solve(17, 2, &["ultra_min=-3"]).unwrap_err().to_string(), "Can't set `ultra_min` to `-3`: invalid digit found in string");
+        assert_eq!(solve(15, 2, &["boxes=-1"]).unwrap_err().to_string(), "Can't set `boxes` to `-1`: invalid digit found in string");
+    }
+}
@@ -0,0 +1,84 @@
+use std::{fs, io::ErrorKind, path::{Path, PathBuf}};
+
+use aoc_common::Result;
+use serde::{Deserialize, Serialize};
+
+// Confirmed answers for one puzzle input, stored next to it as `<input>.answers.json`
+#[derive(Default, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+pub enum Check {
+    Unknown,
+    Correct,
+    Changed { expected: String },
+}
+
+impl Check {
+    pub fn mark(&self) -> &'static str {
+        match self {
+            Check::Unknown => " ",
+            Check::Correct => "✔",
+            Check::Changed { .. } => "✘",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Check::Unknown => "unknown",
+            Check::Correct => "correct",
+            Check::Changed { .. } => "changed",
+        }
+    }
+}
+
+impl Ledger {
+    pub fn path(input: &Path) -> PathBuf {
+        input.with_extension("answers.json")
+    }
+
+    // No ledger yet just means nothing has been confirmed for this input
+    pub fn load(input: &Path) -> Result<Ledger> {
+        let path = Ledger::path(input);
+        match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn save(&self, input: &Path) -> Result<()> {
+        fs::write(Ledger::path(input), serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn check(&self, part: u32, answer: &str) -> Check {
+        match self.answer(part) {
+            None => Check::Unknown,
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Changed { expected: expected.clone() },
+        }
+    }
+
+    pub fn record(&mut self, part: u32, answer: &str) {
+        *self.answer_mut(part) = Some(answer.to_string());
+    }
+
+    fn answer(&self, part: u32) -> &Option<String> {
+        match part {
+            1 => &self.part1,
+            _ => &self.part2,
+        }
+    }
+
+    fn answer_mut(&mut self, part: u32) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
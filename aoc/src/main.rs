@@ -0,0 +1,848 @@
+mod bench;
+mod client;
+mod days;
+mod ledger;
+mod output;
+mod params;
+mod pool;
+mod scaffold;
+mod stepper;
+
+use std::{any::Any, cell::Cell, fs, io::{self, IsTerminal, Write}, num::NonZeroUsize, panic::{self, AssertUnwindSafe}, path::{Path, PathBuf}, process::ExitCode, sync::OnceLock, thread, time::{Duration, Instant}};
+
+use aoc_common::{input, params::Override, parse::report, render::Format, trace, Answer, Result};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use bench::Report;
+use client::{Client, Verdict};
+use days::{Day, Parsed, DAYS};
+use ledger::{Check, Ledger};
+use output::Record;
+use params::Overrides;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day or the whole calendar
+    Run(RunArgs),
+    /// Time parsing and each part over several iterations and write a JSON report
+    Bench(BenchArgs),
+    /// Generate a new day from the template and register it with the runner
+    New(NewArgs),
+    /// Print a random, well-formed puzzle input for a day
+    Generate(GenerateArgs),
+    /// Draw the state of a day as text, in color or as PPM/SVG images
+    Show(ShowArgs),
+    /// Walk through a day's simulation step by step, forwards and back
+    Step(StepArgs),
+    /// Download a day's puzzle input from adventofcode.com
+    Fetch(FetchArgs),
+    /// Submit an answer to adventofcode.com, solving the day's input unless it is given
+    Submit(SubmitArgs),
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("target").required(true).args(["day", "all"])))]
+struct Selection {
+    /// Day to run
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: Option<u32>,
+
+    /// Run every registered day and print a summary table
+    #[arg(long)]
+    all: bool,
+
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// Puzzle input: a file, `-` for stdin, a directory of .txt files or a glob; defaults to the day's input.txt
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+}
+
+impl Selection {
+    fn parts(&self) -> Vec<u32> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    fn days(&self) -> Result<Vec<&'static Day>> {
+        match self.day {
+            Some(number) => Ok(vec![days::get(number).ok_or_else(|| format!("Day {number} is not registered"))?]),
+            None => Ok(DAYS.iter().collect()),
+        }
+    }
+
+    fn inputs(&self, day: &Day) -> Result<Vec<PathBuf>> {
+        match &self.input {
+            Some(spec) => input::expand(spec),
+            None => Ok(vec![default_input(day)]),
+        }
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Record the answers of this run as the known-good ones for the input
+    #[arg(long)]
+    confirm: bool,
+
+    /// Threads running days and parts side by side with --all or several inputs, defaults to one per CPU
+    #[arg(long)]
+    jobs: Option<NonZeroUsize>,
+
+    /// How to print the answers; json and csv give one record per part for scripts
+    #[arg(long, value_enum, default_value_t = output::Format::Table)]
+    format: output::Format,
+
+    /// Write what the solvers do step by step to this file, one JSON object per line
+    #[arg(long)]
+    trace: Option<PathBuf>,
+
+    /// How much --trace writes: 1 milestones, 2 every step, 3 every detail
+    #[arg(long, default_value_t = trace::STEP, value_parser = clap::value_parser!(u8).range(1..=3), requires = "trace")]
+    trace_level: u8,
+
+    /// Change a puzzle parameter of the day from its default, e.g. --param expansion_pt2=10; repeatable
+    #[arg(long = "param", value_name = "NAME=VALUE", conflicts_with = "all")]
+    params: Vec<Override>,
+
+    /// JSON file of puzzle parameters to change by day, e.g. {"11": {"expansion_pt2": 10}}
+    #[arg(long, value_name = "FILE")]
+    params_file: Option<PathBuf>,
+}
+
+impl RunArgs {
+    fn table(&self) -> bool {
+        self.format == output::Format::Table
+    }
+
+    // --param on top of the file
+    fn overrides(&self) -> Result<Overrides> {
+        let mut overrides = match &self.params_file {
+            Some(path) => Overrides::load(path)?,
+            None => Overrides::default(),
+        };
+        if let Some(day) = self.selection.day {
+            overrides.add(day, &self.params);
+        }
+        Ok(overrides)
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Times every phase is repeated
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+
+    /// Where to write the JSON report
+    #[arg(long, default_value = "bench.json")]
+    output: PathBuf,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day of the new puzzle
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Crate name after the day number, e.g. never_tell_me_the_odds
+    #[arg(long)]
+    name: String,
+
+    /// Puzzle title, defaults to the name in title case
+    #[arg(long)]
+    title: Option<String>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Same seed, same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big the input gets: lines, bricks, side of a map, ... depending on the day
+    #[arg(long, default_value_t = 20)]
+    size: usize,
+
+    /// Write the input to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct ShowArgs {
+    /// Day to draw
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Puzzle input file or `-` for stdin, defaults to the day's input.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// ascii, ansi, ppm or svg; defaults to ansi in a terminal and ascii otherwise
+    #[arg(long)]
+    format: Option<Format>,
+
+    /// Write to this file instead of stdout, numbered files when there are several frames; images need one
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Pixels per cell in images
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..=64))]
+    scale: u64,
+}
+
+#[derive(Args)]
+struct StepArgs {
+    /// Day to step through
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Puzzle input file, defaults to the day's input.txt; stdin takes the commands
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// ascii or ansi; defaults to ansi in a terminal and ascii otherwise
+    #[arg(long)]
+    format: Option<Format>,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download the input of
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Where to write the input, defaults to the day's input.txt
+    #[arg(long)]
+    output: Option<PathBuf>,
+
+    /// Replace an input that is already there
+    #[arg(long)]
+    force: bool,
+
+    /// Session cookie, defaults to $AOC_SESSION
+    #[arg(long)]
+    session: Option<String>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day of the answer
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+
+    /// Part the answer is for
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: u32,
+
+    /// Answer to submit instead of solving the input
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Puzzle input to solve, defaults to the day's input.txt; a right answer is recorded in its ledger
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Session cookie, defaults to $AOC_SESSION
+    #[arg(long)]
+    session: Option<String>,
+}
+
+struct PartResult {
+    part: u32,
+    // Failures are rendered into their report right away, so results can come back from other threads
+    answer: std::result::Result<Answer, String>,
+    // From before the input was parsed, or waited for, to the answer; `elapsed` is the part alone
+    started: Instant,
+    finished: Instant,
+    elapsed: Duration,
+}
+
+// An input parsed for all the parts run on it, or the report of why it couldn't be
+type ParseResult = std::result::Result<Box<dyn Parsed>, String>;
+
+// A row of the summary table: the parts of one day on one input
+struct Row<'a> {
+    day: &'a Day,
+    path: PathBuf,
+    // Puzzle parameters changed from the defaults
+    params: &'a [Override],
+    // Leading 37 columns of the row
+    heading: String,
+    // Names the row in problems reported on stderr
+    label: String,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    quiet_solver_panics();
+
+    let result = match cli.command {
+        Command::Run(args) => traced(&args, || if args.selection.all { run_all(&args) } else { run_day(&args) }),
+        Command::Bench(args) => bench(&args),
+        Command::New(args) => new_day(&args),
+        Command::Generate(args) => generate(&args),
+        Command::Show(args) => show(&args),
+        Command::Step(args) => step(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn traced(args: &RunArgs, run: impl FnOnce() -> Result<bool>) -> Result<bool> {
+    let Some(path) = &args.trace else {
+        return run();
+    };
+
+    trace::start(path, args.trace_level).map_err(|e| format!("Can't write the trace to {}: {e}", path.display()))?;
+    let result = run();
+    trace::finish().map_err(|e| format!("Can't write the trace to {}: {e}", path.display()))?;
+    result
+}
+
+fn run_day(args: &RunArgs) -> Result<bool> {
+    let number = args.selection.day.expect("clap requires --day without --all");
+    let day = days::get(number).ok_or_else(|| format!("Day {number} is not registered"))?;
+
+    let paths = args.selection.inputs(day)?;
+    if paths.len() > 1 {
+        return run_batch(args, day, &paths);
+    }
+
+    let path = &paths[0];
+    let input = input::read_input_from(path)?;
+    let overrides = args.overrides()?;
+    let params = overrides.of(day.day);
+
+    if args.table() {
+        println!("Day {}: {}", day.day, day.title);
+    }
+
+    let started = Instant::now();
+    let parsed = parse_input(day, path, &input, params);
+    let results: Vec<_> = args.selection.parts().into_iter().map(|part| run_part(day, part, path, &input, &parsed, started)).collect();
+    let checks = check_answers(path, &results, args.confirm, params)?;
+
+    let mut success = true;
+    for (result, check) in results.iter().zip(&checks) {
+        match &result.answer {
+            Ok(answer) if args.table() => println!("Part {} answer: {} {}({})", result.part, answer, mark(check), format_duration(result.elapsed)),
+            Ok(_) => (),
+            Err(e) => {
+                if args.table() {
+                    println!("Part {} failed ({})", result.part, format_duration(result.elapsed));
+                }
+                eprintln!("{e}");
+                success = false;
+            }
+        }
+
+        if let Check::Changed { expected } = check {
+            eprintln!("Part {} answer changed, the ledger has {}", result.part, expected);
+            success = false;
+        }
+    }
+
+    if !args.table() {
+        print!("{}", output::render(args.format, &records_of(day, path, &input, &results, &checks)));
+    } else if args.confirm {
+        println!("Answers recorded in {}", Ledger::path(path).display());
+    }
+
+    Ok(success)
+}
+
+fn run_all(args: &RunArgs) -> Result<bool> {
+    if args.table() {
+        println!("{:>3}  {:<32} {:>16}   {:>10} {:>16}   {:>10} {:>10}", "Day", "Title", "Part 1", "Time", "Part 2", "Time", "Wall");
+    }
+
+    let overrides = args.overrides()?;
+    let rows: Vec<_> = DAYS.iter()
+        .map(|day| Row { day, path: default_input(day), params: overrides.of(day.day), heading: format!("{:>3}  {:<32}", day.day, day.title), label: format!("Day {}", day.day) })
+        .collect();
+
+    run_rows(args, &rows, |time, wall| println!("{:>3}  {:<32} {:>59} {:>10}", "", "Total", time, wall))
+}
+
+// One row per input file, e.g. to compare the inputs of several accounts
+fn run_batch(args: &RunArgs, day: &Day, paths: &[PathBuf]) -> Result<bool> {
+    if args.table() {
+        println!("Day {}: {}", day.day, day.title);
+        println!("{:<37} {:>16}   {:>10} {:>16}   {:>10} {:>10}", "Input", "Part 1", "Time", "Part 2", "Time", "Wall");
+    }
+
+    let overrides = args.overrides()?;
+    let rows: Vec<_> = paths.iter()
+        .map(|path| Row { day, path: path.clone(), params: overrides.of(day.day), heading: format!("{:<37}", path.display()), label: path.display().to_string() })
+        .collect();
+
+    run_rows(args, &rows, |time, wall| println!("{:<37} {:>59} {:>10}", "Total", time, wall))
+}
+
+// Runs the parts of every row on a thread pool and prints each row once it and every row above it are done,
+// followed by `total` with the summed time of all parts and the wall-clock time of the whole run.
+// JSON and CSV records are printed all at once at the end instead
+fn run_rows(args: &RunArgs, rows: &[Row], total: impl Fn(String, String)) -> Result<bool> {
+    let parts = args.selection.parts();
+    let inputs: Vec<_> = rows.iter().map(|row| input::read_input_from(&row.path)).collect();
+
+    let jobs: Vec<(usize, u32)> = inputs.iter().enumerate()
+        .filter(|(_, input)| input.is_ok())
+        .flat_map(|(i, _)| parts.iter().map(move |&part| (i, part)))
+        .collect();
+    // Whichever part of a row runs first parses its input, the other one waits for it
+    let parsed: Vec<OnceLock<ParseResult>> = rows.iter().map(|_| OnceLock::new()).collect();
+    let tasks = jobs.iter()
+        .map(|&(i, part)| {
+            let (row, input, parsed) = (&rows[i], inputs[i].as_deref().unwrap_or_default(), &parsed[i]);
+            move || {
+                let started = Instant::now();
+                let parsed = parsed.get_or_init(|| trace::in_part(row.day.day, part, &row.path, || parse_input(row.day, &row.path, input, row.params)));
+                run_part(row.day, part, &row.path, input, parsed, started)
+            }
+        })
+        .collect();
+
+    let mut results: Vec<Vec<PartResult>> = rows.iter().map(|_| Vec::new()).collect();
+    let mut pending: Vec<usize> = inputs.iter().map(|input| if input.is_ok() { parts.len() } else { 0 }).collect();
+    let mut next = 0;
+    let mut success = true;
+    let mut time = Duration::ZERO;
+    let mut records = Vec::new();
+    let start = Instant::now();
+
+    let mut print_ready = |results: &mut Vec<Vec<PartResult>>, pending: &[usize]| -> Result<()> {
+        while next < rows.len() && pending[next] == 0 {
+            let (row, input) = (&rows[next], &inputs[next]);
+            if let Err(e) = input {
+                eprintln!("{}: {}", row.label, e);
+                success = false;
+            }
+
+            let row_results = &mut results[next];
+            row_results.sort_by_key(|r| r.part);
+            let checks = check_answers(&row.path, row_results, args.confirm, row.params)?;
+            success &= report_problems(&row.label, row_results, &checks);
+            if args.table() {
+                let wall = wall_clock(row_results).map_or("-".to_string(), format_duration);
+                println!("{}{} {wall:>10}", row.heading, answer_columns(row_results, &checks));
+            } else if let Ok(input) = input {
+                records.extend(records_of(row.day, &row.path, input, row_results, &checks));
+            }
+
+            time += row_results.iter().map(|r| r.elapsed).sum::<Duration>();
+            next += 1;
+        }
+
+        Ok(())
+    };
+
+    print_ready(&mut results, &pending)?;
+    let workers = args.jobs.unwrap_or_else(pool::default_workers);
+    pool::run(workers, tasks, |job, result| {
+        let row = jobs[job].0;
+        results[row].push(result);
+        pending[row] -= 1;
+        print_ready(&mut results, &pending)
+    })?;
+
+    match args.format {
+        output::Format::Table => total(format_duration(time), format_duration(start.elapsed())),
+        format => print!("{}", output::render(format, &records)),
+    }
+    Ok(success)
+}
+
+// From the first part of a row starting to the last one finishing
+fn wall_clock(results: &[PartResult]) -> Option<Duration> {
+    let start = results.iter().map(|r| r.started).min()?;
+    let end = results.iter().map(|r| r.finished).max()?;
+    Some(end - start)
+}
+
+// Answer and time columns of a summary row
+fn answer_columns(results: &[PartResult], checks: &[Check]) -> String {
+    let mut columns = String::new();
+    for part in 1..=2 {
+        match results.iter().zip(checks).find(|(r, _)| r.part == part) {
+            Some((PartResult { answer: Ok(answer), elapsed, .. }, check)) => {
+                columns += &format!(" {:>16} {} {:>10}", answer, check.mark(), format_duration(*elapsed));
+            },
+            Some((PartResult { answer: Err(_), elapsed, .. }, _)) => {
+                columns += &format!(" {:>16}   {:>10}", "error", format_duration(*elapsed));
+            },
+            None => columns += &format!(" {:>16}   {:>10}", "-", "-"),
+        }
+    }
+
+    columns
+}
+
+// Failed parts and changed answers go to stderr under `label`, false if there were any
+fn report_problems(label: &str, results: &[PartResult], checks: &[Check]) -> bool {
+    let mut success = true;
+    for (result, check) in results.iter().zip(checks) {
+        if let Err(e) = &result.answer {
+            eprintln!("{label} part {}:\n{e}", result.part);
+            success = false;
+        }
+        if let Check::Changed { expected } = check {
+            eprintln!("{label} part {}: answer changed, the ledger has {expected}", result.part);
+            success = false;
+        }
+    }
+
+    success
+}
+
+fn records_of(day: &Day, path: &Path, input: &str, results: &[PartResult], checks: &[Check]) -> Vec<Record> {
+    let hash = output::sha256(input);
+    results.iter().zip(checks)
+        .map(|(r, check)| Record::new(day.day, r.part, &r.answer, r.elapsed, path, &hash, check))
+        .collect()
+}
+
+fn bench(args: &BenchArgs) -> Result<bool> {
+    let iterations = args.iterations as usize;
+    let mut results = Report::new(iterations);
+
+    println!("{:>3}  {:<32} {:<6} {:>10} {:>10} {:>10}", "Day", "Title", "Phase", "Min", "Median", "Max");
+
+    let mut success = true;
+    for day in args.selection.days()? {
+        for path in args.selection.inputs(day)? {
+            let input = match input::read_input_from(&path) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Day {}: {}", day.day, e);
+                    success = false;
+                    continue;
+                }
+            };
+
+            let timings = match catch_solver(|| (day.bench)(&input, &args.selection.parts(), iterations)) {
+                Ok(Ok(timings)) => timings,
+                Ok(Err(e)) => {
+                    eprintln!("Day {}:\n{}", day.day, report(&e, &path, &input));
+                    success = false;
+                    continue;
+                }
+                Err(payload) => {
+                    eprintln!("Day {}: {}", day.day, panic_message(payload.as_ref()));
+                    success = false;
+                    continue;
+                }
+            };
+
+            for (i, (phase, stats)) in timings.phases().into_iter().enumerate() {
+                let (number, title) = if i == 0 { (day.day.to_string(), day.title) } else { (String::new(), "") };
+                println!("{:>3}  {:<32} {:<6} {:>10} {:>10} {:>10}", number, title, phase,
+                    format_duration(stats.min), format_duration(stats.median), format_duration(stats.max));
+            }
+
+            results.days.push(bench::DayReport { day: day.day, title: day.title, input: path.display().to_string(), timings });
+        }
+    }
+
+    results.write(&args.output)?;
+    println!("Report written to {}", args.output.display());
+
+    Ok(success)
+}
+
+// Compares the answers against the ledger of the input, or records them as the known-good ones
+fn check_answers(path: &Path, results: &[PartResult], confirm: bool, params: &[Override]) -> Result<Vec<Check>> {
+    // Piped input has nowhere to keep a ledger
+    if input::is_stdin(path) {
+        if confirm {
+            return Err("Answers read from stdin can't be recorded, pass the input file instead".into());
+        }
+        return Ok(results.iter().map(|_| Check::Unknown).collect());
+    }
+
+    // The ledger has the answers to the puzzle as posed, not to a what-if
+    if !params.is_empty() {
+        if confirm {
+            return Err("Answers to changed puzzle parameters can't be recorded, run without them".into());
+        }
+        return Ok(results.iter().map(|_| Check::Unknown).collect());
+    }
+
+    let mut ledger = Ledger::load(path)?;
+    let checks = results.iter()
+        .map(|r| match &r.answer {
+            Ok(answer) if confirm => {
+                ledger.record(r.part, &answer.to_string());
+                Check::Correct
+            },
+            Ok(answer) => ledger.check(r.part, &answer.to_string()),
+            Err(_) => Check::Unknown,
+        })
+        .collect();
+
+    if confirm && results.iter().any(|r| r.answer.is_ok()) {
+        ledger.save(path)?;
+    }
+
+    Ok(checks)
+}
+
+fn mark(check: &Check) -> String {
+    match check {
+        Check::Unknown => String::new(),
+        check => format!("{} ", check.mark()),
+    }
+}
+
+fn new_day(args: &NewArgs) -> Result<bool> {
+    if let Some(day) = days::get(args.day) {
+        return Err(format!("Day {} is already registered as {}", day.day, day.dir).into());
+    }
+
+    let title = args.title.clone().unwrap_or_else(|| scaffold::title_from_name(&args.name));
+    let dir = scaffold::new_day(workspace(), args.day, &args.name, &title)?;
+
+    println!("Created {} for day {}: {}", dir.display(), args.day, title);
+    println!("Paste the puzzle input into {} and the example into fixtures/example.txt", input::INPUT_FILE);
+
+    Ok(true)
+}
+
+fn generate(args: &GenerateArgs) -> Result<bool> {
+    let day = days::get(args.day).ok_or_else(|| format!("Day {} is not registered", args.day))?;
+    let input = (day.generate)(args.seed, args.size);
+
+    match &args.output {
+        Some(path) => fs::write(path, input)?,
+        None => print!("{input}"),
+    }
+
+    Ok(true)
+}
+
+fn show(args: &ShowArgs) -> Result<bool> {
+    let day = days::get(args.day).ok_or_else(|| format!("Day {} is not registered", args.day))?;
+    let Some(draw) = day.show else {
+        let days = DAYS.iter().filter(|d| d.show.is_some()).map(|d| d.day.to_string()).collect::<Vec<_>>();
+        return Err(format!("Day {} has nothing to draw, try one of days {}", day.day, days.join(", ")).into());
+    };
+
+    let format = args.format.unwrap_or(if io::stdout().is_terminal() { Format::Ansi } else { Format::Ascii });
+    if format.is_image() && args.output.is_none() {
+        return Err(format!("{format} images are written to a file, pass --output").into());
+    }
+
+    let path = args.input.clone().unwrap_or_else(|| default_input(day));
+    let input = input::read_input_from(&path)?;
+    let frames = match catch_solver(|| draw(&input)) {
+        Ok(Ok(frames)) => frames,
+        Ok(Err(e)) => return Err(report(&e, &path, &input).into()),
+        Err(payload) => return Err(panic_message(payload.as_ref()).into()),
+    };
+
+    let scale = args.scale as usize;
+    match &args.output {
+        Some(output) if frames.len() == 1 => {
+            format.write(output, &frames[0], scale)?;
+            println!("{} written to {}", frames[0].caption, output.display());
+        },
+        Some(output) => {
+            let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+            let extension = output.extension().map_or(format.extension().into(), |e| e.to_string_lossy());
+            for (i, frame) in frames.iter().enumerate() {
+                format.write(&output.with_file_name(format!("{stem}-{:03}.{extension}", i + 1)), frame, scale)?;
+            }
+            println!("{} frames written to {}", frames.len(), output.with_file_name(format!("{stem}-*.{extension}")).display());
+        },
+        None => {
+            let mut stdout = io::stdout().lock();
+            let printed = frames.iter().try_for_each(|frame| {
+                writeln!(stdout, "{}", frame.caption)?;
+                stdout.write_all(&format.render(frame, scale))?;
+                writeln!(stdout)
+            });
+
+            // Piping into `head` and the like closes stdout early, which is fine
+            match printed {
+                Err(e) if e.kind() == io::ErrorKind::BrokenPipe => (),
+                printed => printed?,
+            }
+        },
+    }
+
+    Ok(true)
+}
+
+fn step(args: &StepArgs) -> Result<bool> {
+    let day = days::get(args.day).ok_or_else(|| format!("Day {} is not registered", args.day))?;
+    let Some(simulate) = day.step else {
+        let days = DAYS.iter().filter(|d| d.step.is_some()).map(|d| d.day.to_string()).collect::<Vec<_>>();
+        return Err(format!("Day {} has no simulation to step through, try one of days {}", day.day, days.join(", ")).into());
+    };
+
+    let terminal = io::stdout().is_terminal();
+    let format = args.format.unwrap_or(if terminal { Format::Ansi } else { Format::Ascii });
+    if format.is_image() {
+        return Err(format!("Steps are drawn as text, {format} isn't").into());
+    }
+
+    let path = args.input.clone().unwrap_or_else(|| default_input(day));
+    if path == Path::new("-") {
+        return Err("stdin takes the step commands, pass the input as a file".into());
+    }
+    let input = input::read_input_from(&path)?;
+    let stepper = match catch_solver(|| simulate(&input)) {
+        Ok(Ok(stepper)) => stepper,
+        Ok(Err(e)) => return Err(report(&e, &path, &input).into()),
+        Err(payload) => return Err(panic_message(payload.as_ref()).into()),
+    };
+
+    stepper::run(stepper, io::stdin().lock(), &mut io::stdout().lock(), format, terminal)?;
+    Ok(true)
+}
+
+fn fetch(args: &FetchArgs) -> Result<bool> {
+    let path = match (&args.output, days::get(args.day)) {
+        (Some(path), _) => path.clone(),
+        (None, Some(day)) => default_input(day),
+        (None, None) => return Err(format!("Day {} is not registered, pass --output or run `aoc new` first", args.day).into()),
+    };
+    if !args.force && fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        return Err(format!("{} is already there, pass --force to replace it", path.display()).into());
+    }
+
+    let input = Client::from_env(args.session.as_deref())?.input(args.day)?;
+    fs::write(&path, &input)?;
+    println!("Day {} input written to {} ({} lines)", args.day, path.display(), input.lines().count());
+
+    Ok(true)
+}
+
+fn submit(args: &SubmitArgs) -> Result<bool> {
+    let client = Client::from_env(args.session.as_deref())?;
+
+    // A given answer isn't tied to an input, so there is no ledger to record it in
+    let (answer, path) = match &args.answer {
+        Some(answer) => (answer.trim().to_string(), None),
+        None => {
+            let day = days::get(args.day).ok_or_else(|| format!("Day {} is not registered, pass --answer", args.day))?;
+            let path = args.input.clone().unwrap_or_else(|| default_input(day));
+            let input = input::read_input_from(&path)?;
+            let result = run_part(day, args.part, &path, &input, &parse_input(day, &path, &input, &[]), Instant::now());
+            let answer = result.answer.map_err(|e| format!("Part {} failed, nothing submitted\n{e}", args.part))?;
+            println!("Day {} part {} answer: {answer}", args.day, args.part);
+            (answer.to_string(), Some(path).filter(|path| !input::is_stdin(path)))
+        },
+    };
+
+    let verdict = client.submit(args.day, args.part, &answer)?;
+    println!("{}", verdict.describe());
+
+    if let (Verdict::Correct, Some(path)) = (&verdict, path) {
+        let mut ledger = Ledger::load(&path)?;
+        ledger.record(args.part, &answer);
+        ledger.save(&path)?;
+        println!("Answer recorded in {}", Ledger::path(&path).display());
+    }
+
+    Ok(verdict == Verdict::Correct)
+}
+
+fn parse_input(day: &Day, path: &Path, input: &str, params: &[Override]) -> ParseResult {
+    match catch_solver(|| (day.parse)(input, params)) {
+        Ok(Ok(parsed)) => Ok(parsed),
+        Ok(Err(e)) => Err(report(&e, path, input)),
+        Err(payload) => Err(format!("error: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+fn run_part(day: &Day, part: u32, path: &Path, input: &str, parsed: &ParseResult, started: Instant) -> PartResult {
+    let solving = Instant::now();
+    let answer = match parsed {
+        Ok(parsed) => match catch_solver(|| trace::in_part(day.day, part, path, || parsed.part(part))) {
+            Ok(Ok(answer)) => Ok(answer),
+            Ok(Err(e)) => Err(report(&e, path, input)),
+            Err(payload) => Err(format!("error: {}", panic_message(payload.as_ref()))),
+        },
+        Err(e) => Err(e.clone()),
+    };
+
+    PartResult { part, answer, started, finished: Instant::now(), elapsed: solving.elapsed() }
+}
+
+thread_local! {
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Panics inside a solver are reported as a failed part instead, any other panic keeps the default report
+fn quiet_solver_panics() {
+    let default = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !SOLVING.get() {
+            default(info);
+        }
+    }));
+}
+
+fn catch_solver<T>(f: impl FnOnce() -> T) -> thread::Result<T> {
+    let outer = SOLVING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SOLVING.set(outer);
+    result
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause");
+
+    format!("solver panicked: {message}")
+}
+
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("runner lives inside the workspace")
+}
+
+fn default_input(day: &Day) -> PathBuf {
+    workspace().join(day.dir).join(input::INPUT_FILE)
+}
+
+fn format_duration(elapsed: Duration) -> String {
+    match elapsed.as_secs_f64() {
+        s if s >= 1. => format!("{s:.2}s"),
+        s if s >= 1e-3 => format!("{:.2}ms", s * 1e3),
+        s => format!("{:.2}µs", s * 1e6),
+    }
+}
@@ -0,0 +1,149 @@
+use std::{path::Path, time::Duration};
+
+use aoc_common::Answer;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+use crate::ledger::Check;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines and tables
+    Table,
+    /// A JSON array with one record per part
+    Json,
+    /// A header line and one row per part
+    Csv,
+}
+
+// One solved (or failed) part, for scripts reading the runner's output
+#[derive(Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    // Null when the part failed, else a string of digits whatever its size, as JSON readers may round big numbers
+    #[serde(serialize_with = "answer")]
+    pub answer: Option<Answer>,
+    #[serde(rename = "elapsed_ns", serialize_with = "nanos")]
+    pub elapsed: Duration,
+    pub input: String,
+    // Same as `sha256sum` of the input file prints
+    pub input_sha256: String,
+    // `correct`, `changed` or `unknown` against the ledger of the input
+    pub check: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(day: u32, part: u32, answer: &std::result::Result<Answer, String>, elapsed: Duration, input: &Path, hash: &str, check: &Check) -> Record {
+        Record {
+            day,
+            part,
+            answer: answer.as_ref().ok().cloned(),
+            elapsed,
+            input: input.display().to_string(),
+            input_sha256: hash.to_string(),
+            check: check.name(),
+            error: answer.as_ref().err().cloned(),
+        }
+    }
+}
+
+pub fn sha256(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+// Tables are printed line by line as the parts finish instead
+pub fn render(format: Format, records: &[Record]) -> String {
+    match format {
+        Format::Table => unreachable!("tables are not rendered from records"),
+        Format::Json => serde_json::to_string_pretty(records).expect("records always serialize") + "\n",
+        Format::Csv => csv(records),
+    }
+}
+
+fn csv(records: &[Record]) -> String {
+    let mut out = String::from("day,part,answer,elapsed_ns,input,input_sha256,check,error\n");
+    for r in records {
+        let answer = r.answer.as_ref().map(|a| a.to_string()).unwrap_or_default();
+        let fields = [
+            r.day.to_string(), r.part.to_string(), answer, r.elapsed.as_nanos().to_string(),
+            r.input.clone(), r.input_sha256.clone(), r.check.to_string(), r.error.clone().unwrap_or_default(),
+        ];
+        out += &fields.iter().map(|field| quote(field)).collect::<Vec<_>>().join(",");
+        out += "\n";
+    }
+
+    out
+}
+
+// RFC 4180: fields with a comma, quote or line break are quoted, quotes inside doubled
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn answer<S: Serializer>(answer: &Option<Answer>, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    match answer {
+        Some(answer) => serializer.collect_str(answer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn nanos<S: Serializer>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, time::Duration};
+
+    use aoc_common::Answer;
+
+    use super::{render, sha256, Format, Record};
+    use crate::ledger::Check;
+
+    fn records() -> Vec<Record> {
+        let hash = sha256("abc");
+        vec![
+            Record::new(1, 1, &Ok(Answer::Int(142)), Duration::from_micros(5), Path::new("input.txt"), &hash, &Check::Correct),
+            Record::new(1, 2, &Err("error: \"x\", y".to_string()), Duration::from_nanos(7), Path::new("input.txt"), &hash, &Check::Unknown),
+        ]
+    }
+
+    #[test]
+    fn hashes_like_sha256sum() {
+        assert_eq!(sha256("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn json_has_exact_answers() {
+        let json: serde_json::Value = serde_json::from_str(&render(Format::Json, &records())).unwrap();
+
+        assert_eq!(json[0]["answer"], "142");
+        assert_eq!(json[0]["elapsed_ns"], 5000);
+        assert_eq!(json[0]["check"], "correct");
+        assert!(json[0].get("error").is_none());
+        assert!(json[1]["answer"].is_null());
+        assert_eq!(json[1]["error"], "error: \"x\", y");
+
+        let big = Record::new(1, 1, &Ok(Answer::from(u128::MAX)), Duration::ZERO, Path::new("input.txt"), "", &Check::Unknown);
+        let json: serde_json::Value = serde_json::from_str(&render(Format::Json, &[big])).unwrap();
+        assert_eq!(json[0]["answer"], u128::MAX.to_string());
+    }
+
+    #[test]
+    fn csv_quotes_awkward_fields() {
+        let csv = render(Format::Csv, &records());
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "day,part,answer,elapsed_ns,input,input_sha256,check,error");
+        assert!(lines[1].starts_with("1,1,142,5000,input.txt,ba7816bf"));
+        assert!(lines[2].ends_with(",unknown,\"error: \"\"x\"\", y\""));
+    }
+}
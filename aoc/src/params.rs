@@ -0,0 +1,68 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use aoc_common::{params::Override, Result};
+use serde_json::{Map, Value};
+
+use crate::days;
+
+// Puzzle parameters changed for a run, by day. The file maps days to parameters and their values,
+// e.g. `{"11": {"expansion_pt2": 10}, "21": {"steps_pt1": 6}}`, `--param` adds to the day being run
+#[derive(Default)]
+pub struct Overrides(HashMap<u32, Vec<Override>>);
+
+impl Overrides {
+    pub fn load(path: &Path) -> Result<Overrides> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let file: HashMap<u32, Map<String, Value>> = serde_json::from_str(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        let mut overrides = Overrides::default();
+        for (day, params) in file {
+            if days::get(day).is_none() {
+                return Err(format!("{}: day {day} is not registered", path.display()).into());
+            }
+
+            // Numbers and strings alike, the day parses the text into the parameter's type
+            let params = params.into_iter()
+                .map(|(name, value)| match value {
+                    Value::String(value) => Override { name, value },
+                    value => Override { name, value: value.to_string() },
+                })
+                .collect::<Vec<_>>();
+            overrides.add(day, &params);
+        }
+
+        Ok(overrides)
+    }
+
+    pub fn add(&mut self, day: u32, params: &[Override]) {
+        self.0.entry(day).or_default().extend_from_slice(params);
+    }
+
+    pub fn of(&self, day: u32) -> &[Override] {
+        self.0.get(&day).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use aoc_common::params::Override;
+
+    use super::Overrides;
+
+    #[test]
+    fn loads_params_by_day() {
+        let path = env::temp_dir().join(format!("aoc_params_{}.json", process::id()));
+        fs::write(&path, r#"{"11": {"expansion_pt2": 10}, "21": {"steps_pt1": "6"}}"#).unwrap();
+
+        let mut overrides = Overrides::load(&path).unwrap();
+        overrides.add(11, &["expansion_pt1=3".parse().unwrap()]);
+        fs::remove_file(&path).unwrap();
+
+        let param = |name: &str, value: &str| Override { name: name.to_string(), value: value.to_string() };
+        assert_eq!(overrides.of(11), [param("expansion_pt2", "10"), param("expansion_pt1", "3")]);
+        assert_eq!(overrides.of(21), [param("steps_pt1", "6")]);
+        assert!(overrides.of(3).is_empty());
+    }
+}
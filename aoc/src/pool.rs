@@ -0,0 +1,86 @@
+use std::{num::NonZeroUsize, sync::{mpsc, Mutex}, thread};
+
+pub fn default_workers() -> NonZeroUsize {
+    thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+// Runs the tasks on `workers` threads, taking them in order, and hands every result to `done` on the
+// calling thread as soon as it is ready. A worker waits for its result to be taken before it takes the next
+// task, so once `done` returns an error and the results stop being taken no worker starts another one
+pub fn run<T: Send, E>(
+    workers: NonZeroUsize,
+    tasks: Vec<impl FnOnce() -> T + Send>,
+    mut done: impl FnMut(usize, T) -> Result<(), E>,
+) -> Result<(), E> {
+    let workers = workers.get().min(tasks.len());
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let (sender, receiver) = mpsc::sync_channel(0);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let (queue, sender) = (&queue, sender.clone());
+            scope.spawn(move || loop {
+                let Some((i, task)) = queue.lock().expect("tasks run outside the lock").next() else {
+                    break;
+                };
+                if sender.send((i, task())).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let result = receiver.iter().try_for_each(|(i, result)| done(i, result));
+        // Fails the sends of the workers still running a task, which then stop
+        drop(receiver);
+        result
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroUsize, sync::atomic::{AtomicUsize, Ordering}, thread, time::Duration};
+
+    use super::run;
+
+    #[test]
+    fn every_task_runs_once() {
+        let tasks = (0..20u64).map(|n| move || {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        }).collect();
+
+        let mut results = vec![None; 20];
+        run(NonZeroUsize::new(4).unwrap(), tasks, |i, square| {
+            results[i] = Some(square);
+            Ok::<_, ()>(())
+        }).unwrap();
+
+        assert_eq!(results, (0..20).map(|n| Some(n * n)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn errors_stop_the_run() {
+        let started = AtomicUsize::new(0);
+        let tasks = (0..100).map(|n| {
+            let started = &started;
+            move || {
+                started.fetch_add(1, Ordering::SeqCst);
+                n
+            }
+        }).collect();
+        let mut started_at_error = 0;
+
+        let result = run(NonZeroUsize::new(2).unwrap(), tasks, |_, n| {
+            if n == 5 {
+                started_at_error = started.load(Ordering::SeqCst);
+                return Err(n);
+            }
+            Ok(())
+        });
+
+        // Each of the two workers may have taken one more task while the error was being handled
+        assert_eq!(result, Err(5));
+        assert!(started.load(Ordering::SeqCst) <= started_at_error + 2);
+    }
+}
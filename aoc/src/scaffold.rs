@@ -0,0 +1,176 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use aoc_common::{input, Result};
+
+const TEMPLATE: &str = "advent_calendar_template";
+const FIXTURE: &str = "fixtures/example.txt";
+
+// Copies the template into `advent_<day>_<name>` and registers it in the workspace and the runner
+pub fn new_day(workspace: &Path, day: u32, name: &str, title: &str) -> Result<PathBuf> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') {
+        return Err(format!("Name `{name}` has to be snake_case, like `never_tell_me_the_odds`").into());
+    }
+
+    let krate = format!("advent_{day}_{name}");
+    let dir = workspace.join(&krate);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let template = workspace.join(TEMPLATE);
+    let manifest = fs::read_to_string(template.join("Cargo.toml"))?.replace(TEMPLATE, &krate);
+    let lib = fs::read_to_string(template.join("src/lib.rs"))?.replace("DayN", &format!("Day{day}"));
+    let generate = fs::read_to_string(template.join("src/generate.rs"))?;
+    let main = fs::read_to_string(template.join("src/main.rs"))?
+        .replace(TEMPLATE, &krate)
+        .replace("DayN", &format!("Day{day}"));
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("fixtures"))?;
+    fs::create_dir_all(dir.join("tests"))?;
+    fs::write(dir.join("Cargo.toml"), manifest)?;
+    fs::write(dir.join("src/lib.rs"), lib)?;
+    fs::write(dir.join("src/main.rs"), main)?;
+    fs::write(dir.join("src/generate.rs"), generate)?;
+    fs::write(dir.join(input::INPUT_FILE), "")?;
+    fs::write(dir.join(FIXTURE), "")?;
+    fs::write(dir.join("tests/examples.rs"), test_stub(&krate, day))?;
+    fs::write(dir.join("tests/round_trip.rs"), round_trip_stub(&krate, day))?;
+
+    register_member(&workspace.join("Cargo.toml"), &krate)?;
+    register_dependency(&workspace.join("aoc/Cargo.toml"), &krate)?;
+    register_day(&workspace.join("aoc/src/days.rs"), &krate, day, title)?;
+
+    Ok(dir)
+}
+
+// "never_tell_me_the_odds" -> "Never Tell Me The Odds"
+pub fn title_from_name(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Fails until the example from the puzzle description is pasted in along with its answers
+fn test_stub(krate: &str, day: u32) -> String {
+    format!(r#"use {krate}::Day{day};
+use aoc_common::testing::{{part1, part2}};
+
+const EXAMPLE: &str = include_str!("../{FIXTURE}");
+
+#[test]
+fn part1_example() {{
+    assert_eq!(part1::<Day{day}>(EXAMPLE), "TODO: answer from the puzzle description");
+}}
+
+#[test]
+fn part2_example() {{
+    assert_eq!(part2::<Day{day}>(EXAMPLE), "TODO: answer from the puzzle description");
+}}
+"#)
+}
+
+// New days go right before the template, which stays the last member
+fn register_member(manifest: &Path, krate: &str) -> Result<()> {
+    let text = fs::read_to_string(manifest)?;
+    let template = format!("    \"{TEMPLATE}\",\n");
+    let index = text.find(&template).ok_or_else(|| format!("{} does not list {TEMPLATE}", manifest.display()))?;
+
+    fs::write(manifest, format!("{}    \"{krate}\",\n{}", &text[..index], &text[index..]))?;
+    Ok(())
+}
+
+fn register_dependency(manifest: &Path, krate: &str) -> Result<()> {
+    let text = fs::read_to_string(manifest)?;
+    let last = text.lines().rfind(|line| line.starts_with("advent_"))
+        .ok_or_else(|| format!("{} has no advent_ dependencies", manifest.display()))?;
+    let index = text.rfind(last).expect("line comes from the text") + last.len();
+
+    fs::write(manifest, format!("{}\n{krate} = {{ path = \"../{krate}\" }}{}", &text[..index], &text[index..]))?;
+    Ok(())
+}
+
+// Keeps the registry in calendar order
+fn register_day(registry: &Path, krate: &str, day: u32, title: &str) -> Result<()> {
+    let text = fs::read_to_string(registry)?;
+    let entry = format!("    Day {{
+        day: {day},
+        title: \"{title}\",
+        dir: \"{krate}\",
+        parse: parse::<{krate}::Day{day}>,
+        bench: bench::measure::<{krate}::Day{day}>,
+        generate: {krate}::generate::generate,
+        show: None,
+        step: None,
+    }},
+");
+
+    let later = (day + 1..=25).find_map(|later| text.find(&format!("    Day {{\n        day: {later},\n")));
+    let index = match later {
+        Some(index) => index,
+        None => text.rfind("];").ok_or_else(|| format!("{} has no DAYS list", registry.display()))?,
+    };
+
+    fs::write(registry, format!("{}{entry}{}", &text[..index], &text[index..]))?;
+    Ok(())
+}
+
+// Keeps passing as long as `print` writes what `parse` reads
+fn round_trip_stub(krate: &str, day: u32) -> String {
+    format!(r#"use {krate}::{{generate::generate, Day{day}}};
+use aoc_common::testing::round_trip;
+
+#[test]
+fn printed_input_parses_back() {{
+    round_trip::<Day{day}>(0..50, |seed| generate(seed, 1 + seed as usize % 10));
+}}
+"#)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{new_day, title_from_name};
+
+    #[test]
+    fn titles_from_names() {
+        assert_eq!(title_from_name("never_tell_me_the_odds"), "Never Tell Me The Odds");
+    }
+
+    #[test]
+    fn scaffolds_and_registers_a_day() {
+        let workspace = env::temp_dir().join(format!("aoc_scaffold_{}", process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(workspace.join("advent_calendar_template/src")).unwrap();
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        fs::write(workspace.join("Cargo.toml"), "members = [\n    \"advent_1_trebuchet\",\n    \"advent_calendar_template\",\n]\n").unwrap();
+        fs::write(workspace.join("advent_calendar_template/Cargo.toml"), "name = \"advent_calendar_template\"\n").unwrap();
+        fs::write(workspace.join("advent_calendar_template/src/lib.rs"), "pub struct DayN;\n").unwrap();
+        fs::write(workspace.join("advent_calendar_template/src/main.rs"), "use advent_calendar_template::DayN;\n").unwrap();
+        fs::write(workspace.join("advent_calendar_template/src/generate.rs"), "pub fn generate() {}\n").unwrap();
+        fs::write(workspace.join("aoc/Cargo.toml"), "[dependencies]\nadvent_1_trebuchet = { path = \"../advent_1_trebuchet\" }\n").unwrap();
+        fs::write(workspace.join("aoc/src/days.rs"), "pub const DAYS: &[Day] = &[\n    Day {\n        day: 1,\n    },\n];\n").unwrap();
+
+        let dir = new_day(&workspace, 24, "never_tell_me_the_odds", "Never Tell Me The Odds").unwrap();
+
+        assert_eq!(fs::read_to_string(dir.join("src/main.rs")).unwrap(), "use advent_24_never_tell_me_the_odds::Day24;\n");
+        assert_eq!(fs::read_to_string(dir.join("input.txt")).unwrap(), "");
+        assert!(fs::read_to_string(dir.join("tests/examples.rs")).unwrap().contains("part1::<Day24>(EXAMPLE)"));
+        assert!(fs::read_to_string(dir.join("tests/round_trip.rs")).unwrap().contains("round_trip::<Day24>(0..50"));
+        assert!(fs::read_to_string(workspace.join("Cargo.toml")).unwrap()
+            .contains("    \"advent_24_never_tell_me_the_odds\",\n    \"advent_calendar_template\","));
+        assert!(fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap()
+            .ends_with("advent_24_never_tell_me_the_odds = { path = \"../advent_24_never_tell_me_the_odds\" }\n"));
+        assert!(fs::read_to_string(workspace.join("aoc/src/days.rs")).unwrap()
+            .contains("    },\n    Day {\n        day: 24,\n        title: \"Never Tell Me The Odds\","));
+        assert!(new_day(&workspace, 24, "never_tell_me_the_odds", "").is_err());
+
+        fs::remove_dir_all(&workspace).unwrap();
+    }
+}
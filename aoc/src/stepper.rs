@@ -0,0 +1,209 @@
+use std::{collections::VecDeque, io::{BufRead, Write}};
+
+use aoc_common::{render::{Format, Frame, Stepper}, Result};
+
+// Frames kept to step back through, the stepper itself only goes forward
+const HISTORY: usize = 1000;
+
+const HELP: &str = "enter or n [N]: forward N steps, b [N]: back N steps, g STEP: go to a step, c CYCLE: go to the end of a cycle, q: quit";
+
+#[derive(PartialEq, Eq, Debug)]
+enum Command {
+    Forward(usize),
+    Back(usize),
+    Go(usize),
+    Cycle(usize),
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Option<Command> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("n");
+        let count = match words.next() {
+            Some(word) => Some(word.parse().ok()?),
+            None => None,
+        };
+        if words.next().is_some() {
+            return None;
+        }
+
+        match (command, count) {
+            ("n", count) => Some(Command::Forward(count.unwrap_or(1))),
+            ("b", count) => Some(Command::Back(count.unwrap_or(1))),
+            ("g", Some(step)) => Some(Command::Go(step)),
+            ("c", Some(cycle)) => Some(Command::Cycle(cycle)),
+            ("q", None) => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+// The steps taken so far, the last `HISTORY` of them drawn, and the one being looked at
+struct Session {
+    stepper: Box<dyn Stepper>,
+    frames: VecDeque<Frame>,
+    latest: usize,
+    at: usize,
+    finished: bool,
+}
+
+impl Session {
+    fn new(stepper: Box<dyn Stepper>) -> Session {
+        let frames = VecDeque::from([stepper.frame()]);
+        Session { stepper, frames, latest: 0, at: 0, finished: false }
+    }
+
+    fn first(&self) -> usize {
+        self.latest + 1 - self.frames.len()
+    }
+
+    // Only the frames that will still be kept at `target` are drawn, so long jumps just step
+    fn go(&mut self, target: usize) -> Option<String> {
+        while self.latest < target && !self.finished {
+            if !self.stepper.step() {
+                self.finished = true;
+                break;
+            }
+            self.latest += 1;
+            if target - self.latest < HISTORY {
+                self.frames.push_back(self.stepper.frame());
+                if self.frames.len() > HISTORY {
+                    self.frames.pop_front();
+                }
+            } else {
+                self.frames.clear();
+            }
+        }
+        if self.frames.is_empty() {
+            self.frames.push_back(self.stepper.frame());
+        }
+
+        self.at = target.clamp(self.first(), self.latest);
+        if target < self.first() {
+            Some(format!("Only the last {} steps are kept", self.frames.len()))
+        } else if target > self.latest {
+            Some(format!("The simulation is over after step {}", self.latest))
+        } else {
+            None
+        }
+    }
+
+    fn frame(&self) -> &Frame {
+        &self.frames[self.at - self.first()]
+    }
+}
+
+// Walks `stepper` by the commands read one per line, drawing the step it gets to after each
+pub fn run(stepper: Box<dyn Stepper>, commands: impl BufRead, out: &mut impl Write, format: Format, clear: bool) -> Result<()> {
+    let mut session = Session::new(stepper);
+    let mut note = Some(HELP.to_string());
+    let mut lines = commands.lines();
+
+    loop {
+        if clear {
+            write!(out, "\x1b[2J\x1b[H")?;
+        }
+        writeln!(out, "Step {}: {}", session.at, session.frame().caption)?;
+        out.write_all(&format.render(session.frame(), 1))?;
+        if let Some(note) = note.take() {
+            writeln!(out, "{note}")?;
+        }
+        write!(out, "> ")?;
+        out.flush()?;
+
+        let Some(line) = lines.next().transpose()? else {
+            writeln!(out)?;
+            return Ok(());
+        };
+        note = match Command::parse(&line) {
+            Some(Command::Forward(count)) => session.go(session.at.saturating_add(count)),
+            Some(Command::Back(count)) => session.go(session.at.saturating_sub(count)),
+            Some(Command::Go(step)) => session.go(step),
+            Some(Command::Cycle(cycle)) => session.go(cycle.saturating_mul(session.stepper.cycle())),
+            Some(Command::Quit) => return Ok(()),
+            None => Some(HELP.to_string()),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::render::{Format, Frame, Stepper};
+
+    use super::{run, Command, HELP, HISTORY};
+
+    // Counts to `end`, two steps to a cycle
+    struct Counter {
+        count: usize,
+        end: usize,
+    }
+
+    impl Stepper for Counter {
+        fn step(&mut self) -> bool {
+            if self.count == self.end {
+                return false;
+            }
+            self.count += 1;
+            true
+        }
+
+        fn frame(&self) -> Frame {
+            Frame::text(format!("count {}", self.count), &[])
+        }
+
+        fn cycle(&self) -> usize {
+            2
+        }
+    }
+
+    fn session(end: usize, commands: &str) -> String {
+        let mut out = Vec::new();
+        run(Box::new(Counter { count: 0, end }), commands.as_bytes(), &mut out, Format::Ascii, false).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    fn steps(out: &str) -> Vec<&str> {
+        out.lines().filter_map(|line| line.trim_start_matches("> ").strip_prefix("Step ")).collect()
+    }
+
+    #[test]
+    fn commands_parse() {
+        assert_eq!(Command::parse(""), Some(Command::Forward(1)));
+        assert_eq!(Command::parse("n 5"), Some(Command::Forward(5)));
+        assert_eq!(Command::parse(" b "), Some(Command::Back(1)));
+        assert_eq!(Command::parse("g 12"), Some(Command::Go(12)));
+        assert_eq!(Command::parse("c 3"), Some(Command::Cycle(3)));
+        assert_eq!(Command::parse("q"), Some(Command::Quit));
+        assert_eq!(Command::parse("g"), None);
+        assert_eq!(Command::parse("n x"), None);
+        assert_eq!(Command::parse("n 1 2"), None);
+    }
+
+    #[test]
+    fn steps_forward_and_back() {
+        let out = session(10, "\nn 3\nb 2\nc 3\ng 4\nq\nn\n");
+
+        assert_eq!(steps(&out), ["0: count 0", "1: count 1", "4: count 4", "2: count 2", "6: count 6", "4: count 4"]);
+        assert!(out.starts_with("Step 0: count 0\n"));
+        assert!(out.contains(HELP));
+    }
+
+    #[test]
+    fn stops_where_the_simulation_does() {
+        let out = session(3, "n 5\nb\nwhat\n");
+
+        assert_eq!(steps(&out), ["0: count 0", "3: count 3", "2: count 2", "2: count 2"]);
+        assert!(out.contains("The simulation is over after step 3"));
+        assert_eq!(out.matches(HELP).count(), 2);
+    }
+
+    #[test]
+    fn keeps_the_latest_steps() {
+        let out = session(usize::MAX, &format!("g {}\ng 1\n", HISTORY * 5));
+
+        let first = HISTORY * 4 + 1;
+        assert_eq!(steps(&out), ["0: count 0".to_string(), format!("{}: count {}", HISTORY * 5, HISTORY * 5), format!("{first}: count {first}")]);
+        assert!(out.contains(&format!("Only the last {HISTORY} steps are kept")));
+    }
+}
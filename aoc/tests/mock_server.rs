@@ -0,0 +1,114 @@
+use std::{env, fs, io::{BufRead, BufReader}, path::{Path, PathBuf}, process::{self, Child, Command, Output, Stdio}, thread, time::Duration};
+
+const INPUT: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+// `aoc_mock` on a free port, stopped when dropped
+struct Mock {
+    server: Child,
+    url: String,
+}
+
+impl Mock {
+    fn start(fixtures: &Path) -> Mock {
+        let mut server = Command::new(env!("CARGO_BIN_EXE_aoc_mock"))
+            .args(["--port", "0", "--cooldown", "1", "--fixtures"])
+            .arg(fixtures)
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(server.stdout.take().unwrap()).read_line(&mut line).unwrap();
+        let url = line.trim().strip_prefix("Listening on ").expect("the mock announces its address").to_string();
+
+        Mock { server, url }
+    }
+
+    fn aoc(&self, session: &str, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).env("AOC_SERVER", &self.url).env("AOC_SESSION", session).output().unwrap()
+    }
+}
+
+impl Drop for Mock {
+    fn drop(&mut self) {
+        let _ = self.server.kill();
+        let _ = self.server.wait();
+    }
+}
+
+// Day 1 with the part 1 example as the input, which has the same answer for both parts
+fn fixtures(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc_mock_{name}_{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("work")).unwrap();
+    fs::write(dir.join("day1.txt"), INPUT).unwrap();
+    fs::write(dir.join("day1.answers.json"), "{\"part1\": \"142\", \"part2\": \"142\"}\n").unwrap();
+
+    dir
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn fetches_solves_and_submits() {
+    let dir = fixtures("flow");
+    let mock = Mock::start(&dir);
+    let input = dir.join("work/input.txt");
+    let input_arg = input.to_str().unwrap();
+
+    let fetched = mock.aoc("mock-session", &["fetch", "--day", "1", "--output", input_arg]);
+    assert!(fetched.status.success(), "{}", stderr(&fetched));
+    assert_eq!(fs::read_to_string(&input).unwrap(), INPUT);
+
+    let again = mock.aoc("mock-session", &["fetch", "--day", "1", "--output", input_arg]);
+    assert!(!again.status.success());
+    assert!(stderr(&again).contains("pass --force"));
+
+    let low = mock.aoc("mock-session", &["submit", "--day", "1", "--part", "1", "--answer", "100"]);
+    assert!(!low.status.success());
+    assert!(stdout(&low).contains("too low"), "{}", stdout(&low));
+
+    let early = mock.aoc("mock-session", &["submit", "--day", "1", "--part", "1", "--input", input_arg]);
+    assert!(stdout(&early).contains("Answered too recently, wait 1s"), "{}", stdout(&early));
+
+    thread::sleep(Duration::from_millis(1100));
+    let right = mock.aoc("mock-session", &["submit", "--day", "1", "--part", "1", "--input", input_arg]);
+    assert!(right.status.success(), "{}{}", stdout(&right), stderr(&right));
+    assert!(stdout(&right).contains("Day 1 part 1 answer: 142\nThat's the right answer!"));
+    assert!(fs::read_to_string(dir.join("work/input.answers.json")).unwrap().contains("\"part1\": \"142\""));
+
+    let solved = mock.aoc("mock-session", &["submit", "--day", "1", "--part", "1", "--answer", "142"]);
+    assert!(stdout(&solved).contains("is it solved already?"));
+
+    let high = mock.aoc("mock-session", &["submit", "--day", "1", "--part", "2", "--answer", "999"]);
+    assert!(stdout(&high).contains("too high"));
+
+    drop(mock);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn reports_bad_sessions_and_locked_days() {
+    let dir = fixtures("errors");
+    let mock = Mock::start(&dir);
+    let output = dir.join("work/input.txt");
+    let output_arg = output.to_str().unwrap();
+
+    let rejected = mock.aoc("stale", &["fetch", "--day", "1", "--output", output_arg]);
+    assert!(!rejected.status.success());
+    assert!(stderr(&rejected).contains("session cookie was rejected"));
+
+    let locked = mock.aoc("mock-session", &["fetch", "--day", "2", "--output", output_arg]);
+    assert!(!locked.status.success());
+    assert!(stderr(&locked).contains("Day 2 has no input yet"));
+    assert!(!output.exists());
+
+    drop(mock);
+    fs::remove_dir_all(&dir).unwrap();
+}
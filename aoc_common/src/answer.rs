@@ -0,0 +1,73 @@
+use std::fmt;
+
+use crate::BigUint;
+
+// What every part answers: an exact integer, whatever integer type the day computes it in.
+// i128 holds nearly all of them, the few that outgrow it stay exact as a `BigUint`, so nothing is
+// rounded or wrapped on the way to the runner
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Answer {
+    Int(i128),
+    // Only ever past `i128::MAX`, so every number has one form and the derived order holds
+    Big(BigUint),
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i128::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::Big(BigUint::from(n)),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Answer {
+        match n.to_u128() {
+            Some(small) => Answer::from(small),
+            None => Answer::Big(n),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => fmt::Display::fmt(n, f),
+            Answer::Big(n) => fmt::Display::fmt(n, f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+    use crate::BigUint;
+
+    #[test]
+    fn keeps_every_integer_exact() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from(7usize), Answer::from(7u8));
+        assert_eq!(Answer::from(u128::MAX).to_string(), "340282366920938463463374607431768211455");
+    }
+
+    #[test]
+    fn big_answers_that_fit_are_ints() {
+        assert_eq!(Answer::from(BigUint::from(5u64)), Answer::Int(5));
+        assert!(matches!(Answer::from(u128::MAX), Answer::Big(_)));
+        assert!(Answer::from(u128::MAX) > Answer::from(i128::MAX));
+    }
+}
@@ -0,0 +1,155 @@
+use std::{cmp::Ordering, fmt, iter::Product};
+
+// Unsigned integer of any size, for the answers that outgrow the machine integers. Only what those
+// need: multiplying and dividing by machine integers, and printing
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct BigUint {
+    // Little endian digits in base 2^64, no zero digits at the top so every number has one form
+    digits: Vec<u64>,
+}
+
+impl BigUint {
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn mul_small(&self, n: u64) -> BigUint {
+        let mut digits = Vec::with_capacity(self.digits.len() + 1);
+        let mut carry = 0u128;
+        for &digit in &self.digits {
+            let product = digit as u128 * n as u128 + carry;
+            digits.push(product as u64);
+            carry = product >> 64;
+        }
+        digits.push(carry as u64);
+
+        BigUint::trimmed(digits)
+    }
+
+    // Quotient and remainder
+    pub fn div_rem_small(&self, n: u64) -> (BigUint, u64) {
+        assert!(n != 0, "division by zero");
+
+        let mut digits = vec![0; self.digits.len()];
+        let mut rem = 0u128;
+        for (i, &digit) in self.digits.iter().enumerate().rev() {
+            let current = (rem << 64) | digit as u128;
+            digits[i] = (current / n as u128) as u64;
+            rem = current % n as u128;
+        }
+
+        (BigUint::trimmed(digits), rem as u64)
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.digits[..] {
+            [] => Some(0),
+            [low] => Some(low as u128),
+            [low, high] => Some((high as u128) << 64 | low as u128),
+            _ => None,
+        }
+    }
+
+    // Least common multiple of numbers whose multiple may not fit in any machine integer
+    pub fn lcm_of(nums: &[u64]) -> BigUint {
+        nums.iter().fold(BigUint::from(1u64), |acc, &n| {
+            if acc.is_zero() || n == 0 {
+                return BigUint::default();
+            }
+
+            let (_, rem) = acc.div_rem_small(n);
+            acc.mul_small(n / crate::math::gcd_u64(n, rem))
+        })
+    }
+
+    fn trimmed(mut digits: Vec<u64>) -> BigUint {
+        while digits.last() == Some(&0) {
+            _ = digits.pop();
+        }
+        BigUint { digits }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> BigUint {
+        BigUint::trimmed(vec![n])
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> BigUint {
+        BigUint::trimmed(vec![n as u64, (n >> 64) as u64])
+    }
+}
+
+impl Product<u64> for BigUint {
+    fn product<I: Iterator<Item = u64>>(iter: I) -> BigUint {
+        iter.fold(BigUint::from(1u64), |acc, n| acc.mul_small(n))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.digits.len().cmp(&other.digits.len()).then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 19 decimal digits at a time, the most a u64 holds
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.div_rem_small(CHUNK);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+
+        let mut text = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            text += &format!("{chunk:019}");
+        }
+        f.pad_integral(true, "", &text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BigUint;
+
+    #[test]
+    fn prints_past_u128() {
+        let big = BigUint::from(u128::MAX).mul_small(10).mul_small(10);
+
+        assert_eq!(big.to_string(), "34028236692093846346337460743176821145500");
+        assert_eq!(big.to_u128(), None);
+        assert_eq!(BigUint::default().to_string(), "0");
+        assert_eq!(format!("{:>5}", BigUint::from(42u64)), "   42");
+    }
+
+    #[test]
+    fn divides_back() {
+        let big = [u64::MAX, 3, 1 << 40].into_iter().product::<BigUint>();
+
+        assert_eq!(big.div_rem_small(1 << 40), (BigUint::from(u64::MAX as u128 * 3), 0));
+        assert_eq!(big.div_rem_small(10).1, (u64::MAX as u128 * 3 * (1 << 40) % 10) as u64);
+        assert!(big > BigUint::from(u64::MAX as u128 * 3));
+    }
+
+    #[test]
+    fn lcm_outgrows_u128() {
+        let primes = [1_000_000_007, 1_000_000_009, 998_244_353, 1_000_000_021, 1_000_000_033];
+
+        assert_eq!(BigUint::lcm_of(&[4, 6, 10]), BigUint::from(60u64));
+        assert_eq!(BigUint::lcm_of(&primes), primes.into_iter().product());
+        assert_eq!(BigUint::lcm_of(&primes).to_u128(), None);
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt;
+
+// The four ways to move on a map, rows counted from the top so north is up
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    // Clockwise from north
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::North | Dir4::South)
+    }
+
+    // (rows, columns) moved by one step
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir4::North => (-1, 0),
+            Dir4::East => (0, 1),
+            Dir4::South => (1, 0),
+            Dir4::West => (0, -1),
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::North => '^',
+            Dir4::East => '>',
+            Dir4::South => 'v',
+            Dir4::West => '<',
+        }
+    }
+}
+
+impl fmt::Display for Dir4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Dir4::North => "north",
+            Dir4::East => "east",
+            Dir4::South => "south",
+            Dir4::West => "west",
+        };
+        f.write_str(name)
+    }
+}
+
+// The four ways to move and the diagonals in between
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    // Clockwise from north
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North, Dir8::NorthEast, Dir8::East, Dir8::SouthEast, Dir8::South, Dir8::SouthWest, Dir8::West, Dir8::NorthWest,
+    ];
+
+    // An eighth of a turn
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir8::North => (-1, 0),
+            Dir8::NorthEast => (-1, 1),
+            Dir8::East => (0, 1),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (1, 0),
+            Dir8::SouthWest => (1, -1),
+            Dir8::West => (0, -1),
+            Dir8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Dir8 {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+// A position with no map around it to stay on, e.g. the day 18 trench dug from the origin
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Coord {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Coord {
+    pub fn new(row: i64, col: i64) -> Coord {
+        Coord { row, col }
+    }
+
+    // `steps` steps towards `dir`
+    pub fn step(self, dir: Dir4, steps: i64) -> Coord {
+        let (d_row, d_col) = dir.delta();
+        Coord { row: self.row + d_row as i64 * steps, col: self.col + d_col as i64 * steps }
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Coord> {
+        Dir4::ALL.into_iter().map(move |dir| self.step(dir, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Coord, Dir4, Dir8};
+
+    #[test]
+    fn turns_come_back_around() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(Dir8::from(dir).turn_right().turn_right(), Dir8::from(dir.turn_right()));
+        }
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir8::NorthWest.opposite(), Dir8::SouthEast);
+    }
+
+    #[test]
+    fn steps_follow_the_deltas() {
+        let origin = Coord::default();
+
+        assert_eq!(origin.step(Dir4::North, 3), Coord::new(-3, 0));
+        assert_eq!(origin.step(Dir4::East, 2).step(Dir4::South, 1), Coord::new(1, 2));
+        assert_eq!(origin.neighbours4().count(), 4);
+        for dir in Dir8::ALL {
+            let (row, col) = dir.delta();
+            assert_eq!(dir.opposite().delta(), (-row, -col));
+        }
+    }
+}
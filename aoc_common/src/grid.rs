@@ -0,0 +1,189 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::{Dir4, Dir8, ParseResult, Source};
+
+// (row, column), rows counted from the top of the map
+pub type Pos = (usize, usize);
+
+// Up, down, left, right, and all eight in reading order
+pub const NEIGHBOURS4: [Dir4; 4] = [Dir4::North, Dir4::South, Dir4::West, Dir4::East];
+pub const NEIGHBOURS8: [Dir8; 8] = [
+    Dir8::NorthWest, Dir8::North, Dir8::NorthEast, Dir8::West, Dir8::East, Dir8::SouthWest, Dir8::South, Dir8::SouthEast,
+];
+
+// Dense row-major 2D map of the puzzle input
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), rows * cols, "a {rows}x{cols} grid needs {} cells", rows * cols);
+        Grid { rows, cols, cells }
+    }
+
+    // Every line is a row and every char goes through `f`, see `Source::grid`
+    pub fn parse(input: &str, expected: &str, f: impl Fn(char) -> Option<T>) -> ParseResult<Grid<T>> {
+        Source::new(input).grid(input, expected, f)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn in_bounds(&self, row: isize, col: isize) -> bool {
+        (0..self.rows as isize).contains(&row) && (0..self.cols as isize).contains(&col)
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.cells[row * self.cols + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        (row < self.rows && col < self.cols).then(|| &mut self.cells[row * self.cols + col])
+    }
+
+    // `pos` moved by (rows, columns), as long as it stays on the grid
+    pub fn offset(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let (row, col) = (row as isize + d_row, col as isize + d_col);
+        self.in_bounds(row, col).then_some((row as usize, col as usize))
+    }
+
+    // One step towards `dir`, unless that leaves the grid
+    pub fn step(&self, pos: Pos, dir: Dir4) -> Option<Pos> {
+        self.offset(pos, dir.delta())
+    }
+
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4.into_iter().filter_map(move |dir| self.step(pos, dir))
+    }
+
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8.into_iter().filter_map(move |dir| self.offset(pos, dir.delta()))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(col < self.cols, "column {col} is outside of a grid with {} columns", self.cols);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { rows: self.rows, cols: self.cols, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(rows: usize, cols: usize, value: T) -> Grid<T> {
+        Grid { rows, cols, cells: vec![value; rows * cols] }
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.cols).flat_map(|col| self.column(col).cloned()).collect();
+        Grid { rows: self.cols, cols: self.rows, cells }
+    }
+
+    // The left column becomes the top row
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = (0..self.cols).flat_map(|col| self.column(col).rev().cloned()).collect();
+        Grid { rows: self.cols, cols: self.rows, cells }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{pos:?} is outside of a {}x{} grid", self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos:?} is outside of a {rows}x{cols} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", "a letter", |c| c.is_ascii_lowercase().then_some(c)).unwrap()
+    }
+
+    #[test]
+    fn parses_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let error = Grid::parse("abc\nde", "a letter", Some).unwrap_err();
+
+        assert_eq!(error.to_string(), "line 2, column 3: expected a letter, found end of line");
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = grid();
+
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_clockwise().rotate_clockwise().rotate_clockwise().rotate_clockwise(), grid);
+    }
+}
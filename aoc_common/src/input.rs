@@ -0,0 +1,113 @@
+use std::{fs, io::{self, Read}, path::{Path, PathBuf}};
+
+use crate::Result;
+
+// Puzzle input file every day reads from its working directory
+pub const INPUT_FILE: &str = "input.txt";
+
+// Stands for standard input wherever an input path is expected
+pub const STDIN: &str = "-";
+
+pub fn read_input() -> Result<String> {
+    read_input_from(INPUT_FILE)
+}
+
+pub fn read_input_from(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    if is_stdin(path) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(|e| format!("Failed to read stdin: {e}"))?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {e}", path.display()).into())
+}
+
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN)
+}
+
+// `-`, a file, every .txt file of a directory or a glob pattern, in sorted order
+pub fn expand(spec: &Path) -> Result<Vec<PathBuf>> {
+    if is_stdin(spec) || spec.is_file() {
+        return Ok(vec![spec.to_path_buf()]);
+    }
+
+    let mut paths = if spec.is_dir() {
+        fs::read_dir(spec)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<io::Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt"))
+            .collect()
+    } else {
+        let pattern = spec.to_str().ok_or_else(|| format!("{} is not valid UTF-8", spec.display()))?;
+        glob::glob(pattern)
+            .map_err(|e| format!("Bad input pattern {pattern}: {e}"))?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+    };
+
+    if paths.is_empty() {
+        return Err(format!("No puzzle inputs found at {}", spec.display()).into());
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+// Puzzle files differ by whichever editor saved them: drop a BOM, turn CRLF into LF and
+// strip trailing whitespace from every line and the end, so parsers only see `\n` separated lines
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    input.split('\n')
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, process};
+
+    use super::{expand, normalize};
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(normalize("a b\nc\n\nd"), "a b\nc\n\nd");
+        assert_eq!(normalize("a b\r\nc\r\n\r\nd"), "a b\nc\n\nd");
+        assert_eq!(normalize("a\r\nb\nc"), "a\nb\nc");
+    }
+
+    #[test]
+    fn trailing_newline_and_whitespace() {
+        assert_eq!(normalize("a\nb\n"), "a\nb");
+        assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb");
+        assert_eq!(normalize("a  \nb\t\n \n"), "a\nb");
+        assert_eq!(normalize("  a\n  b"), "  a\n  b");
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        assert_eq!(normalize("\u{feff}a\r\nb\r\n"), "a\nb");
+    }
+
+    #[test]
+    fn expands_directories_and_globs() {
+        let dir = env::temp_dir().join(format!("aoc_inputs_{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["b.txt", "a.txt", "a.answers.json"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        assert_eq!(expand(&dir).unwrap(), [dir.join("a.txt"), dir.join("b.txt")]);
+        assert_eq!(expand(&dir.join("b*")).unwrap(), [dir.join("b.txt")]);
+        assert_eq!(expand(&dir.join("a.txt")).unwrap(), [dir.join("a.txt")]);
+        assert_eq!(expand(Path::new("-")).unwrap(), [Path::new("-")]);
+        assert!(expand(&dir.join("c*")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+pub mod answer;
+pub mod bignum;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod params;
+pub mod parse;
+pub mod random;
+pub mod render;
+pub mod search;
+pub mod solution;
+pub mod testing;
+pub mod trace;
+
+pub use answer::Answer;
+pub use bignum::BigUint;
+pub use geometry::{Coord, Dir4, Dir8};
+pub use grid::{Grid, Pos};
+pub use params::{Configurable, Params};
+pub use parse::{ParseError, ParseResult, Source};
+pub use random::Rng;
+pub use solution::{Print, Solution};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
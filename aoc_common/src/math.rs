@@ -0,0 +1,30 @@
+// Least Common Multiple Calculation - https://en.wikipedia.org/wiki/Least_common_multiple
+// None once the multiple doesn't fit in an i64, `BigUint::lcm_of` has no such limit
+pub fn lcm_of(nums: &[i64]) -> Option<i64> {
+    nums.iter().try_fold(1, |acc, x| lcm(acc, *x))
+}
+
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b).and_then(i64::checked_abs)
+}
+
+// Greatest Common Divisor Calculation - https://en.wikipedia.org/wiki/Greatest_common_divisor
+pub fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn gcd_u64(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd_u64(b, a % b)
+    }
+}
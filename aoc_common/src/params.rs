@@ -0,0 +1,106 @@
+use std::{fmt::Debug, str::FromStr};
+
+use crate::{Result, Solution};
+
+// One puzzle parameter changed from its default, `name=value` on the command line
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Override {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Override {
+    type Err = String;
+
+    fn from_str(text: &str) -> std::result::Result<Override, String> {
+        let (name, value) = text.split_once('=').ok_or_else(|| format!("expected NAME=VALUE, got `{text}`"))?;
+        Ok(Override { name: name.trim().to_string(), value: value.trim().to_string() })
+    }
+}
+
+// The numbers a day's puzzle text fixes, e.g. how often day 14 spins the platform, so what-if runs don't
+// need the source edited. `params!` declares the struct and writes this impl
+pub trait Params: Default + Clone + Debug {
+    const NAMES: &'static [&'static str];
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()>;
+
+    // The defaults with every override applied in order
+    fn with(overrides: &[Override]) -> Result<Self> {
+        let mut params = Self::default();
+        for Override { name, value } in overrides {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+// Days whose parts take parameters, their plain `part1` and `part2` solve with the defaults
+pub trait Configurable: Solution {
+    type Params: Params;
+
+    fn part1_with(input: &Self::Input, params: &Self::Params) -> Result<Self::Answer1>;
+    fn part2_with(input: &Self::Input, params: &Self::Params) -> Result<Self::Answer2>;
+}
+
+// `params! { pub struct Day14Params { cycles: usize = 1_000_000_000 } }` declares the struct with public
+// fields and implements `Default` and `Params` for it, every field set by its name from text
+#[macro_export]
+macro_rules! params {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident { $($field:ident: $ty:ty = $default:expr),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Debug)]
+        $vis struct $name {
+            $(pub $field: $ty),*
+        }
+
+        impl Default for $name {
+            fn default() -> $name {
+                $name { $($field: $default),* }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            const NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn set(&mut self, name: &str, value: &str) -> $crate::Result<()> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = value.parse().map_err(|e| format!("Can't set `{name}` to `{value}`: {e}"))?;
+                    },)*
+                    _ => return Err(format!("No parameter `{name}`, this day has {}", Self::NAMES.join(", ")).into()),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Override, Params};
+
+    params! {
+        struct Example {
+            cycles: usize = 1_000_000_000,
+            unfold: usize = 5,
+        }
+    }
+
+    #[test]
+    fn overrides_apply_in_order() {
+        let overrides = ["unfold=2", "cycles = 10", "unfold=3"].map(|text| text.parse::<Override>().unwrap());
+
+        assert_eq!(Example::with(&overrides).unwrap(), Example { cycles: 10, unfold: 3 });
+        assert_eq!(Example::with(&[]).unwrap(), Example::default());
+    }
+
+    #[test]
+    fn bad_overrides_say_why() {
+        let error = |text: &str| Example::with(&[text.parse().unwrap()]).unwrap_err().to_string();
+
+        assert_eq!(error("spins=3"), "No parameter `spins`, this day has cycles, unfold");
+        assert_eq!(error("cycles=-1"), "Can't set `cycles` to `-1`: invalid digit found in string");
+        assert!("cycles".parse::<Override>().is_err());
+    }
+}
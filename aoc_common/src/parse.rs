@@ -0,0 +1,228 @@
+use std::{error::Error, fmt, path::{Path, PathBuf}, str::FromStr};
+
+use crate::{grid::Grid, input};
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+// Position of a token in the puzzle input, 1-based like an editor shows it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    // From 0-based line and column indices, as produced by `lines().enumerate()` and `chars().enumerate()`
+    pub fn at(line: usize, column: usize) -> Location {
+        Location { file: None, line: line + 1, column: column + 1 }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file.display(), self.line, self.column),
+            None => write!(f, "line {}, column {}", self.line, self.column),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    // A token is there, but it is not what the puzzle format allows
+    Unexpected { location: Location, found: String, expected: String },
+    // The line or section ended before the expected token
+    Missing { location: Location, expected: String },
+}
+
+impl ParseError {
+    pub fn unexpected_char(line: usize, column: usize, found: char, expected: &str) -> ParseError {
+        ParseError::Unexpected { location: Location::at(line, column), found: found.to_string(), expected: expected.to_string() }
+    }
+
+    pub fn location(&self) -> &Location {
+        match self {
+            ParseError::Unexpected { location, .. } | ParseError::Missing { location, .. } => location,
+        }
+    }
+
+    pub fn with_file(mut self, path: &Path) -> ParseError {
+        match &mut self {
+            ParseError::Unexpected { location, .. } | ParseError::Missing { location, .. } => location.file = Some(path.to_path_buf()),
+        }
+
+        self
+    }
+
+    // Compiler style report with the offending line and a caret under the bad token
+    pub fn render(&self, input: &str) -> String {
+        let location = self.location();
+        let width = match self {
+            ParseError::Unexpected { found, .. } => found.chars().count().max(1),
+            ParseError::Missing { .. } => 1,
+        };
+
+        let line_no = location.line.to_string();
+        let gutter = " ".repeat(line_no.len());
+        let line = input.lines().nth(location.line - 1).unwrap_or("");
+        let pointer = format!("{}{}", " ".repeat(location.column - 1), "^".repeat(width));
+
+        format!("error: {}\n{gutter}--> {location}\n{gutter} |\n{line_no} | {line}\n{gutter} | {pointer}", self.message())
+    }
+
+    fn message(&self) -> String {
+        match self {
+            ParseError::Unexpected { found, expected, .. } if found.is_empty() => format!("expected {expected}, found nothing"),
+            ParseError::Unexpected { found, expected, .. } => format!("expected {expected}, found `{found}`"),
+            ParseError::Missing { expected, .. } => format!("expected {expected}, found end of line"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location(), self.message())
+    }
+}
+
+impl Error for ParseError {}
+
+// Parse errors point at the offending character of the input, anything else is printed as is
+pub fn report(error: &crate::Error, path: &Path, input: &str) -> String {
+    match error.downcast_ref::<ParseError>() {
+        Some(e) => e.clone().with_file(path).render(&input::normalize(input)),
+        None => format!("error: {error}"),
+    }
+}
+
+// The whole puzzle input; tokens sliced out of it can be traced back to their line and column
+#[derive(Clone, Copy)]
+pub struct Source<'a> {
+    text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(text: &'a str) -> Source<'a> {
+        Source { text }
+    }
+
+    // `token` has to be a slice of the source text, anything else is reported at the start
+    pub fn location(&self, token: &str) -> Location {
+        let offset = (token.as_ptr() as usize).checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(0);
+
+        let before = &self.text[..offset];
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Location::at(line, before[line_start..].chars().count())
+    }
+
+    pub fn unexpected(&self, token: &str, expected: &str) -> ParseError {
+        ParseError::Unexpected { location: self.location(token), found: token.to_string(), expected: expected.to_string() }
+    }
+
+    // Points just past `after`, where the missing token should have been
+    pub fn missing(&self, after: &str, expected: &str) -> ParseError {
+        ParseError::Missing { location: self.location(&after[after.len()..]), expected: expected.to_string() }
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> ParseResult<T> {
+        token.parse().map_err(|_| self.unexpected(token, "a number"))
+    }
+
+    pub fn split_once<'b>(&self, text: &'b str, delimiter: &str) -> ParseResult<(&'b str, &'b str)> {
+        text.split_once(delimiter).ok_or_else(|| self.missing(text, &format!("`{delimiter}`")))
+    }
+
+    // Maps every char of `text` through `f`, reporting the first one it rejects
+    pub fn chars<T>(&self, text: &str, expected: &str, f: impl Fn(char) -> Option<T>) -> ParseResult<Vec<T>> {
+        text.char_indices()
+            .map(|(i, c)| f(c).ok_or_else(|| self.unexpected(&text[i..i + c.len_utf8()], expected)))
+            .collect()
+    }
+
+    // One row per line of `text`, every row has to be as wide as the first one
+    pub fn grid<T>(&self, text: &str, expected: &str, f: impl Fn(char) -> Option<T>) -> ParseResult<Grid<T>> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+        for line in text.lines() {
+            let row = self.chars(line, expected, &f)?;
+            if rows == 0 {
+                cols = row.len();
+            } else if row.len() < cols {
+                return Err(self.missing(line, expected));
+            } else if row.len() > cols {
+                let (extra, _) = line.char_indices().nth(cols).expect("row is longer than the first one");
+                return Err(self.unexpected(&line[extra..], "end of line"));
+            }
+
+            cells.extend(row);
+            rows += 1;
+        }
+
+        Ok(Grid::new(rows, cols, cells))
+    }
+
+    // The n-th whitespace separated token of `text`
+    pub fn token<'b>(&self, text: &'b str, n: usize, expected: &str) -> ParseResult<&'b str> {
+        text.split_whitespace().nth(n).ok_or_else(|| self.missing(text, expected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{ParseError, Source};
+
+    #[test]
+    fn locates_tokens() {
+        let input = "32T3K 765\nT55J5 6x4";
+        let source = Source::new(input);
+        let bid = input.lines().nth(1).unwrap().split_once(' ').unwrap().1;
+
+        let error = source.number::<usize>(bid).unwrap_err();
+
+        assert_eq!(error.location().line, 2);
+        assert_eq!(error.location().column, 7);
+        assert_eq!(error.to_string(), "line 2, column 7: expected a number, found `6x4`");
+    }
+
+    #[test]
+    fn missing_delimiter_points_past_the_line() {
+        let input = "broadcaster a, b";
+        let source = Source::new(input);
+
+        let error = source.split_once(input, "->").unwrap_err();
+
+        assert_eq!(error.location().column, 17);
+        assert_eq!(error.to_string(), "line 1, column 17: expected `->`, found end of line");
+    }
+
+    #[test]
+    fn reports_first_rejected_char() {
+        let input = "LRLX";
+        let source = Source::new(input);
+
+        let error = source.chars(input, "`L` or `R`", |c| matches!(c, 'L' | 'R').then_some(c)).unwrap_err();
+
+        assert_eq!(error.to_string(), "line 1, column 4: expected `L` or `R`, found `X`");
+    }
+
+    #[test]
+    fn renders_caret_under_token() {
+        let input = "#.#\n#X#";
+        let error = ParseError::unexpected_char(1, 1, 'X', "a tile").with_file(Path::new("input.txt"));
+
+        assert_eq!(error.render(input), "\
+error: expected a tile, found `X`
+ --> input.txt:2:2
+  |
+2 | #X#
+  |  ^");
+    }
+}
@@ -0,0 +1,205 @@
+use std::{collections::{HashMap, VecDeque}, ops::{Bound, RangeBounds}};
+
+use crate::{Grid, Pos};
+
+// Small seeded generator for the puzzle input generators; SplitMix64 keeps a seed producing
+// the same input on every platform and release - https://prng.di.unimi.it/splitmix64.c
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in `range`, which must not be empty
+    pub fn range<R: RangeBounds<u64>>(&mut self, range: R) -> u64 {
+        let low = match range.start_bound() {
+            Bound::Included(&low) => low,
+            Bound::Excluded(&low) => low + 1,
+            Bound::Unbounded => 0,
+        };
+        let high = match range.end_bound() {
+            Bound::Included(&high) => high,
+            Bound::Excluded(&high) => high.checked_sub(1).expect("range must not be empty"),
+            Bound::Unbounded => u64::MAX,
+        };
+        assert!(low <= high, "range must not be empty");
+
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..n as u64) as usize
+    }
+
+    // True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+// Corners of a random loop that never touches or crosses itself, walked clockwise from the top left.
+// It outlines a blob of cells grown inside a `cells`x`cells` square, corner (r, c) being the top left
+// of cell (r, c); corners where the loop goes straight on are included
+pub fn simple_loop(rng: &mut Rng, cells: usize) -> Vec<Pos> {
+    outline(&grow_blob(rng, cells.max(1)))
+}
+
+// Grows a blob from the middle one cell at a time, never closing a hole or touching itself diagonally,
+// so its outline is a single loop that never crosses itself
+fn grow_blob(rng: &mut Rng, cells: usize) -> Grid<bool> {
+    let mut blob = Grid::filled(cells, cells, false);
+    blob[(cells / 2, cells / 2)] = true;
+
+    let target = cells * cells * rng.range(30..=60) as usize / 100;
+    let mut size = 1;
+    for _ in 0..cells * cells * 20 {
+        if size >= target {
+            break;
+        }
+
+        let pos = (rng.below(cells), rng.below(cells));
+        if blob[pos] || !blob.neighbours4(pos).any(|n| blob[n]) {
+            continue;
+        }
+
+        blob[pos] = true;
+        if pinched(&blob, pos) || has_hole(&blob) {
+            blob[pos] = false;
+        } else {
+            size += 1;
+        }
+    }
+
+    blob
+}
+
+// Two cells of the blob touching only at a corner
+fn pinched(blob: &Grid<bool>, (row, col): (usize, usize)) -> bool {
+    let cell = |r: isize, c: isize| blob.in_bounds(r, c) && blob[(r as usize, c as usize)];
+    let (row, col) = (row as isize, col as isize);
+
+    [(-1, -1), (-1, 0), (0, -1), (0, 0)].into_iter().any(|(dr, dc)| {
+        let (top, left) = (row + dr, col + dc);
+        let square = [cell(top, left), cell(top, left + 1), cell(top + 1, left), cell(top + 1, left + 1)];
+        square == [true, false, false, true] || square == [false, true, true, false]
+    })
+}
+
+// Some empty cell that can't reach the border
+fn has_hole(blob: &Grid<bool>) -> bool {
+    let mut seen = Grid::filled(blob.rows(), blob.cols(), false);
+    let mut queue = blob.positions()
+        .filter(|&(row, col)| row == 0 || col == 0 || row + 1 == blob.rows() || col + 1 == blob.cols())
+        .filter(|&pos| !blob[pos])
+        .collect::<VecDeque<_>>();
+    for &pos in &queue {
+        seen[pos] = true;
+    }
+
+    while let Some(pos) = queue.pop_front() {
+        for next in blob.neighbours4(pos).collect::<Vec<_>>() {
+            if !blob[next] && !seen[next] {
+                seen[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    blob.iter().any(|(pos, cell)| !cell && !seen[pos])
+}
+
+// Corners around the blob in walking order, corner (r, c) being the top left of cell (r, c)
+fn outline(blob: &Grid<bool>) -> Vec<(usize, usize)> {
+    let inside = |row: usize, col: usize| blob.get((row, col)).is_some_and(|cell| *cell);
+
+    // Edges go clockwise around the blob, only the sides of cells without a blob neighbour count
+    let mut edges = HashMap::new();
+    for ((row, col), _) in blob.iter().filter(|(_, cell)| **cell) {
+        if row == 0 || !inside(row - 1, col) {
+            edges.insert((row, col), (row, col + 1));
+        }
+        if !inside(row, col + 1) {
+            edges.insert((row, col + 1), (row + 1, col + 1));
+        }
+        if !inside(row + 1, col) {
+            edges.insert((row + 1, col + 1), (row + 1, col));
+        }
+        if col == 0 || !inside(row, col - 1) {
+            edges.insert((row + 1, col), (row, col));
+        }
+    }
+
+    // Without pinches every corner on the outline starts exactly one edge
+    let first = *edges.keys().min().expect("the blob has at least one cell");
+    let mut corners = vec![first];
+    let mut corner = edges[&first];
+    while corner != first {
+        corners.push(corner);
+        corner = edges[&corner];
+    }
+
+    corners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simple_loop, Rng};
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let first = (0..10).map({ let mut rng = Rng::new(42); move |_| rng.next_u64() }).collect::<Vec<_>>();
+        let second = (0..10).map({ let mut rng = Rng::new(42); move |_| rng.next_u64() }).collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+        assert_ne!(first, (0..10).map({ let mut rng = Rng::new(43); move |_| rng.next_u64() }).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn ranges_stay_in_bounds() {
+        let mut rng = Rng::new(7);
+
+        assert!((0..1000).map(|_| rng.range(3..=5)).all(|n| (3..=5).contains(&n)));
+        assert!((0..1000).map(|_| rng.below(4)).any(|n| n == 3));
+        assert_eq!(rng.range(9..10), 9);
+    }
+
+    #[test]
+    fn loops_are_closed_and_simple() {
+        for seed in 0..20 {
+            let corners = simple_loop(&mut Rng::new(seed), 8);
+            let unique = corners.iter().collect::<std::collections::HashSet<_>>();
+
+            assert_eq!(unique.len(), corners.len());
+            assert_eq!(corners[0], *corners.iter().min().unwrap());
+            for (i, a) in corners.iter().enumerate() {
+                let b = corners[(i + 1) % corners.len()];
+                assert_eq!(a.0.abs_diff(b.0) + a.1.abs_diff(b.1), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use std::{fmt, fs, io, iter, path::Path, str::FromStr};
+
+use crate::{Grid, Pos, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const GREY: Color = Color(110, 110, 110);
+    pub const WHITE: Color = Color(230, 230, 230);
+    pub const RED: Color = Color(220, 50, 47);
+    pub const ORANGE: Color = Color(240, 140, 30);
+    pub const YELLOW: Color = Color(240, 210, 60);
+    pub const GREEN: Color = Color(90, 190, 70);
+    pub const BLUE: Color = Color(60, 120, 220);
+}
+
+// How one cell is drawn: the glyph in text output, the color in the terminal and in images
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Style {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Style {
+    pub fn new(glyph: char, color: Color) -> Style {
+        Style { glyph, color }
+    }
+}
+
+// One picture of a day's state, e.g. the platform after a spin cycle
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<Style>,
+}
+
+impl Frame {
+    // Every cell of `grid` goes through `style`
+    pub fn new<T>(caption: impl Into<String>, grid: &Grid<T>, style: impl Fn(Pos, &T) -> Style) -> Frame {
+        let cells = Grid::new(grid.rows(), grid.cols(), grid.iter().map(|(pos, cell)| style(pos, cell)).collect());
+        Frame { caption: caption.into(), cells }
+    }
+
+    // Lines of text for state that isn't a map, e.g. the pulses of a button press, padded to the longest
+    pub fn text(caption: impl Into<String>, lines: &[(String, Color)]) -> Frame {
+        let cols = lines.iter().map(|(line, _)| line.chars().count()).max().unwrap_or(0);
+        let cells = lines.iter()
+            .flat_map(|(line, color)| line.chars().chain(iter::repeat(' ')).take(cols).map(|glyph| Style::new(glyph, *color)))
+            .collect();
+        Frame { caption: caption.into(), cells: Grid::new(lines.len(), cols, cells) }
+    }
+}
+
+// Days that can draw their state, frame by frame
+pub trait Visualise: Solution {
+    fn frames(input: &Self::Input) -> Vec<Frame>;
+}
+
+// A simulation that goes one step at a time, drawn after every step, e.g. day 14 tilting its platform
+pub trait Stepper {
+    // Takes the next step, false once the simulation is over and nothing changed
+    fn step(&mut self) -> bool;
+    fn frame(&self) -> Frame;
+
+    // Steps in one cycle of the puzzle, e.g. the four tilts of a day 14 spin cycle
+    fn cycle(&self) -> usize {
+        1
+    }
+}
+
+// Days whose simulation can be walked through step by step
+pub trait Simulate: Solution {
+    fn stepper(input: Self::Input) -> Box<dyn Stepper>;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Ascii,
+    Ansi,
+    Ppm,
+    Svg,
+}
+
+impl Format {
+    // Images are binary or too big to read in a terminal
+    pub fn is_image(self) -> bool {
+        matches!(self, Format::Ppm | Format::Svg)
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ascii | Format::Ansi => "txt",
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        }
+    }
+
+    // Images draw every cell as a `scale`x`scale` square
+    pub fn render(self, frame: &Frame, scale: usize) -> Vec<u8> {
+        match self {
+            Format::Ascii => ascii(&frame.cells).into_bytes(),
+            Format::Ansi => ansi(&frame.cells).into_bytes(),
+            Format::Ppm => ppm(&frame.cells, scale),
+            Format::Svg => svg(&frame.cells, scale).into_bytes(),
+        }
+    }
+
+    pub fn write(self, path: &Path, frame: &Frame, scale: usize) -> io::Result<()> {
+        fs::write(path, self.render(frame, scale))
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "ascii" => Ok(Format::Ascii),
+            "ansi" => Ok(Format::Ansi),
+            "ppm" => Ok(Format::Ppm),
+            "svg" => Ok(Format::Svg),
+            _ => Err(format!("unknown format `{s}`, expected ascii, ansi, ppm or svg")),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Format::Ascii => "ascii",
+            Format::Ansi => "ansi",
+            Format::Ppm => "ppm",
+            Format::Svg => "svg",
+        };
+        f.write_str(name)
+    }
+}
+
+pub fn ascii(cells: &Grid<Style>) -> String {
+    cells.map(|style| style.glyph).to_string() + "\n"
+}
+
+// 24-bit terminal colors, only switched when the next cell needs another one
+pub fn ansi(cells: &Grid<Style>) -> String {
+    let mut out = String::new();
+    for row in 0..cells.rows() {
+        let mut current = None;
+        for style in cells.row(row) {
+            if current != Some(style.color) {
+                let Color(r, g, b) = style.color;
+                out += &format!("\x1b[38;2;{r};{g};{b}m");
+                current = Some(style.color);
+            }
+            out.push(style.glyph);
+        }
+        out += "\x1b[0m\n";
+    }
+
+    out
+}
+
+// Binary PPM, which most image viewers and converters read - https://netpbm.sourceforge.net/doc/ppm.html
+pub fn ppm(cells: &Grid<Style>, scale: usize) -> Vec<u8> {
+    let scale = scale.max(1);
+    let mut out = format!("P6\n{} {}\n255\n", cells.cols() * scale, cells.rows() * scale).into_bytes();
+    for row in 0..cells.rows() {
+        let line = cells.row(row).iter()
+            .flat_map(|style| iter::repeat_n(style.color, scale))
+            .flat_map(|Color(r, g, b)| [r, g, b])
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            out.extend_from_slice(&line);
+        }
+    }
+
+    out
+}
+
+// A square per cell on a black background, with the glyph as a tooltip
+pub fn svg(cells: &Grid<Style>, scale: usize) -> String {
+    let scale = scale.max(1);
+    let (width, height) = (cells.cols() * scale, cells.rows() * scale);
+    let mut out = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n");
+    out += &format!("<rect width=\"{width}\" height=\"{height}\" fill=\"black\"/>\n");
+    for ((row, col), style) in cells.iter().filter(|(_, style)| style.color != Color::BLACK) {
+        let Color(r, g, b) = style.color;
+        out += &format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{scale}\" height=\"{scale}\" fill=\"#{r:02x}{g:02x}{b:02x}\"><title>{}</title></rect>\n",
+            col * scale, row * scale, escape(style.glyph),
+        );
+    }
+    out += "</svg>\n";
+
+    out
+}
+
+fn escape(glyph: char) -> String {
+    match glyph {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, Format, Frame, Style};
+    use crate::Grid;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("#.\n.<", "a cell", Some).unwrap();
+        Frame::new("test", &grid, |_, &c| match c {
+            '.' => Style::new(c, Color::BLACK),
+            _ => Style::new(c, Color::RED),
+        })
+    }
+
+    #[test]
+    fn text_lines_are_padded() {
+        let frame = Frame::text("pulses", &[("a -high-> b".to_string(), Color::RED), ("b -low-> c".to_string(), Color::BLUE)]);
+
+        assert_eq!(Format::Ascii.render(&frame, 1), b"a -high-> b\nb -low-> c \n");
+        assert_eq!(frame.cells[(1, 0)].color, Color::BLUE);
+    }
+
+    #[test]
+    fn text_keeps_the_glyphs() {
+        assert_eq!(Format::Ascii.render(&frame(), 1), b"#.\n.<\n");
+
+        let ansi = String::from_utf8(Format::Ansi.render(&frame(), 1)).unwrap();
+        assert_eq!(ansi.lines().next(), Some("\x1b[38;2;220;50;47m#\x1b[38;2;0;0;0m.\x1b[0m"));
+    }
+
+    #[test]
+    fn images_scale_the_cells() {
+        let ppm = Format::Ppm.render(&frame(), 3);
+        let header = b"P6\n6 6\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 6 * 3);
+        assert_eq!(&ppm[header.len()..header.len() + 3], [220, 50, 47]);
+
+        let svg = String::from_utf8(Format::Svg.render(&frame(), 3)).unwrap();
+        assert_eq!(svg.matches("<rect").count(), 3);
+        assert!(svg.contains("<rect x=\"3\" y=\"3\" width=\"3\" height=\"3\" fill=\"#dc322f\"><title>&lt;</title></rect>"));
+    }
+
+    #[test]
+    fn formats_parse_their_names() {
+        for format in [Format::Ascii, Format::Ansi, Format::Ppm, Format::Svg] {
+            assert_eq!(format.to_string().parse::<Format>(), Ok(format));
+        }
+        assert!("png".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,375 @@
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+use crate::trace;
+
+// Anything the searches can walk: from a node to its neighbours, each edge with a small whole cost
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, usize)>;
+
+    // A small number unique to `node`, for graphs that can number theirs. The cheapest path searches then keep
+    // their books in a vector as long as the biggest number instead of a hash map
+    fn index(&self, _node: &Self::Node) -> Option<usize> {
+        None
+    }
+}
+
+// Every node a breadth first search reached, with how it got there
+pub struct Reached<N> {
+    // Steps from the start, and the node it was first reached from
+    parents: HashMap<N, (usize, Option<N>)>,
+}
+
+impl<N: Clone + Eq + Hash> Reached<N> {
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.parents.contains_key(node)
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.parents.get(node).map(|(distance, _)| *distance)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&N, usize)> + '_ {
+        self.parents.iter().map(|(node, (distance, _))| (node, *distance))
+    }
+
+    pub fn nodes(self) -> impl Iterator<Item = N> {
+        self.parents.into_keys()
+    }
+
+    // A shortest path from the start to `node`, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut parent = self.parents.get(node)?.1.clone();
+        while let Some(node) = parent {
+            parent = self.parents[&node].1.clone();
+            path.push(node);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+// Everything reachable from `start`, edge costs are ignored and every edge counts as one step
+pub fn bfs<G: Graph>(graph: &G, start: G::Node) -> Reached<G::Node> {
+    bfs_within(graph, start, usize::MAX)
+}
+
+// Everything at most `steps` steps away from `start`
+pub fn bfs_within<G: Graph>(graph: &G, start: G::Node, steps: usize) -> Reached<G::Node> {
+    let mut bfs = Bfs::new(start);
+    while bfs.distance() < steps && bfs.step(graph) {}
+    trace!(trace::MILESTONE, "reached", "nodes": bfs.reached.len(), "steps": bfs.distance());
+
+    bfs.into_reached()
+}
+
+// A breadth first search that goes one step further at a time, for simulations that show every step.
+// Takes the graph on each step so it can live next to what it searches
+pub struct Bfs<N> {
+    reached: Reached<N>,
+    // The nodes reached on the last step, in the order they were reached
+    frontier: Vec<N>,
+    distance: usize,
+}
+
+impl<N: Clone + Eq + Hash> Bfs<N> {
+    pub fn new(start: N) -> Bfs<N> {
+        Bfs { reached: Reached { parents: HashMap::from([(start.clone(), (0, None))]) }, frontier: vec![start], distance: 0 }
+    }
+
+    // Reaches the nodes one step further, false once there are none and nothing changed
+    pub fn step<G: Graph<Node = N>>(&mut self, graph: &G) -> bool {
+        let mut next = Vec::new();
+        for node in &self.frontier {
+            for (neighbour, _) in graph.neighbours(node) {
+                if !self.reached.parents.contains_key(&neighbour) {
+                    _ = self.reached.parents.insert(neighbour.clone(), (self.distance + 1, Some(node.clone())));
+                    next.push(neighbour);
+                }
+            }
+        }
+        if next.is_empty() {
+            return false;
+        }
+
+        self.frontier = next;
+        self.distance += 1;
+        trace!(trace::DETAIL, "layer", "steps": self.distance, "frontier": self.frontier.len(), "reached": self.reached.len());
+        true
+    }
+
+    // Steps taken so far, the distance of the frontier
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+
+    pub fn frontier(&self) -> &[N] {
+        &self.frontier
+    }
+
+    pub fn reached(&self) -> &Reached<N> {
+        &self.reached
+    }
+
+    pub fn into_reached(self) -> Reached<N> {
+        self.reached
+    }
+}
+
+// The cheapest path from `start` to a node passing `is_goal`, both ends included, and its cost
+pub fn dijkstra<G: Graph>(graph: &G, start: G::Node, is_goal: impl Fn(&G::Node) -> bool) -> Option<(Vec<G::Node>, usize)> {
+    let cheapest = astar(graph, start, is_goal, |_| 0);
+    trace!(trace::MILESTONE, "cheapest", "cost": cheapest.as_ref().map(|(_, cost)| cost), "nodes": cheapest.as_ref().map(|(path, _)| path.len()));
+    cheapest
+}
+
+// Dijkstra led by `heuristic`, which must be consistent: it never drops by more than the cost of a step, and
+// is 0 on goals - https://en.wikipedia.org/wiki/Consistent_heuristic. Finished nodes are never reopened and
+// the bucket cursor never goes back, so a heuristic that is only admissible can miss the cheapest path.
+// Costs are small whole numbers, so the open nodes sit in a bucket per estimated total cost instead of a heap
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> Option<(Vec<G::Node>, usize)> {
+    let mut books = Books { graph, dense: Vec::new(), sparse: HashMap::new() };
+    books.insert(&start, Entry { cost: 0, parent: None, done: false });
+    let mut buckets: Vec<Vec<(G::Node, usize)>> = Vec::new();
+    push(&mut buckets, heuristic(&start), (start, 0));
+
+    let mut estimate = 0;
+    let mut finished = 0;
+    while estimate < buckets.len() {
+        let Some((node, cost)) = buckets[estimate].pop() else {
+            trace!(trace::DETAIL, "bucket", "estimate": estimate, "finished": finished);
+            estimate += 1;
+            continue;
+        };
+
+        // Stale entries of nodes reached cheaper since
+        let entry = books.get_mut(&node).expect("queued nodes are in the books");
+        if cost > entry.cost || entry.done {
+            continue;
+        }
+        entry.done = true;
+        finished += 1;
+
+        if is_goal(&node) {
+            return Some((books.path(node), cost));
+        }
+
+        for (next, step) in graph.neighbours(&node) {
+            let next_cost = cost + step;
+            match books.get_mut(&next) {
+                Some(known) if known.cost <= next_cost => continue,
+                Some(known) => *known = Entry { cost: next_cost, parent: Some(node.clone()), done: false },
+                None => books.insert(&next, Entry { cost: next_cost, parent: Some(node.clone()), done: false }),
+            }
+            push(&mut buckets, next_cost + heuristic(&next), (next, next_cost));
+        }
+    }
+
+    None
+}
+
+fn push<T>(buckets: &mut Vec<Vec<T>>, bucket: usize, item: T) {
+    if buckets.len() <= bucket {
+        buckets.resize_with(bucket + 1, Vec::new);
+    }
+    buckets[bucket].push(item);
+}
+
+// The cheapest cost found so far to every node astar reached, the node it came from and whether it's final
+struct Entry<N> {
+    cost: usize,
+    parent: Option<N>,
+    done: bool,
+}
+
+// The entries by node index when the graph numbers its nodes, by node when it doesn't
+struct Books<'a, G: Graph> {
+    graph: &'a G,
+    dense: Vec<Option<Entry<G::Node>>>,
+    sparse: HashMap<G::Node, Entry<G::Node>>,
+}
+
+impl<G: Graph> Books<'_, G> {
+    fn get(&self, node: &G::Node) -> Option<&Entry<G::Node>> {
+        match self.graph.index(node) {
+            Some(index) => self.dense.get(index)?.as_ref(),
+            None => self.sparse.get(node),
+        }
+    }
+
+    fn get_mut(&mut self, node: &G::Node) -> Option<&mut Entry<G::Node>> {
+        match self.graph.index(node) {
+            Some(index) => self.dense.get_mut(index)?.as_mut(),
+            None => self.sparse.get_mut(node),
+        }
+    }
+
+    fn insert(&mut self, node: &G::Node, entry: Entry<G::Node>) {
+        match self.graph.index(node) {
+            Some(index) => {
+                if self.dense.len() <= index {
+                    self.dense.resize_with(index + 1, || None);
+                }
+                self.dense[index] = Some(entry);
+            },
+            None => _ = self.sparse.insert(node.clone(), entry),
+        }
+    }
+
+    fn path(&self, end: G::Node) -> Vec<G::Node> {
+        let mut path = vec![end];
+        while let Some(parent) = &self.get(path.last().expect("the path has its end")).expect("path nodes are in the books").parent {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        path
+    }
+}
+
+// The costliest path from `start` to `goal` that never visits a node twice, by trying every one of them.
+// Only feasible on small graphs, e.g. a maze shrunk to its junctions
+pub fn longest_path<G: Graph>(graph: &G, start: G::Node, goal: &G::Node) -> Option<(Vec<G::Node>, usize)> {
+    let mut visited = HashSet::from([start.clone()]);
+    let mut path = vec![start];
+    let mut longest = None;
+    walk(graph, goal, &mut visited, &mut path, 0, &mut longest);
+
+    longest
+}
+
+fn walk<G: Graph>(
+    graph: &G,
+    goal: &G::Node,
+    visited: &mut HashSet<G::Node>,
+    path: &mut Vec<G::Node>,
+    cost: usize,
+    longest: &mut Option<(Vec<G::Node>, usize)>,
+) {
+    let node = path.last().expect("the path has its start").clone();
+    if node == *goal {
+        if longest.as_ref().is_none_or(|(_, best)| cost > *best) {
+            trace!(trace::MILESTONE, "longest", "cost": cost, "nodes": path.len());
+            *longest = Some((path.clone(), cost));
+        }
+        return;
+    }
+
+    for (next, step) in graph.neighbours(&node) {
+        if visited.insert(next.clone()) {
+            path.push(next.clone());
+            walk(graph, goal, visited, path, cost + step, longest);
+            _ = path.pop();
+            _ = visited.remove(&next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, bfs_within, dijkstra, longest_path, Bfs, Graph};
+    use crate::{Grid, Pos};
+
+    // Open cells are digits, the cost of stepping onto them
+    struct Map(Grid<Option<usize>>);
+
+    impl Graph for Map {
+        type Node = Pos;
+
+        fn neighbours(&self, &pos: &Pos) -> Vec<(Pos, usize)> {
+            self.0.neighbours4(pos).filter_map(|next| self.0[next].map(|cost| (next, cost))).collect()
+        }
+    }
+
+    // The same map with its cells numbered, for the searches that keep their books in a vector
+    struct Numbered(Map);
+
+    impl Graph for Numbered {
+        type Node = Pos;
+
+        fn neighbours(&self, pos: &Pos) -> Vec<(Pos, usize)> {
+            self.0.neighbours(pos)
+        }
+
+        fn index(&self, &(row, col): &Pos) -> Option<usize> {
+            Some(row * self.0.0.cols() + col)
+        }
+    }
+
+    fn map(text: &str) -> Map {
+        Map(Grid::parse(text, "a digit or `#`", |c| match c {
+            '#' => Some(None),
+            c => c.to_digit(10).map(|d| Some(d as usize)),
+        }).unwrap())
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let map = map("111\n#1#\n111");
+
+        let reached = bfs(&map, (0, 0));
+        assert_eq!(reached.len(), 7);
+        assert_eq!(reached.distance(&(2, 2)), Some(4));
+        assert_eq!(reached.path_to(&(2, 0)), Some(vec![(0, 0), (0, 1), (1, 1), (2, 1), (2, 0)]));
+        assert_eq!(reached.distance(&(1, 0)), None);
+
+        assert_eq!(bfs_within(&map, (0, 0), 2).len(), 4);
+    }
+
+    #[test]
+    fn bfs_steps_one_layer_at_a_time() {
+        let map = map("111\n#1#\n111");
+        let mut search = Bfs::new((0, 0));
+
+        assert!(search.step(&map));
+        assert_eq!(search.frontier(), [(0, 1)]);
+        while search.step(&map) {}
+        assert_eq!(search.distance(), 4);
+        assert_eq!(search.frontier(), [(2, 0), (2, 2)]);
+        assert_eq!(search.reached().len(), bfs(&map, (0, 0)).len());
+    }
+
+    #[test]
+    fn dijkstra_and_astar_find_the_cheapest_path() {
+        let map = map("1911\n1191\n9111");
+        let goal = (2, 3);
+
+        let (path, cost) = dijkstra(&map, (0, 0), |&pos| pos == goal).unwrap();
+        assert_eq!(cost, 5);
+        assert_eq!(path, vec![(0, 0), (1, 0), (1, 1), (2, 1), (2, 2), (2, 3)]);
+
+        let manhattan = |&(row, col): &Pos| goal.0.abs_diff(row) + goal.1.abs_diff(col);
+        assert_eq!(astar(&map, (0, 0), |&pos| pos == goal, manhattan).map(|(_, cost)| cost), Some(5));
+
+        assert_eq!(dijkstra(&map, (0, 0), |&pos| pos == (5, 5)), None);
+
+        let numbered = Numbered(map);
+        assert_eq!(dijkstra(&numbered, (0, 0), |&pos| pos == goal), Some((path, cost)));
+        assert_eq!(dijkstra(&numbered, (0, 0), |&pos| pos == (5, 5)), None);
+    }
+
+    #[test]
+    fn longest_path_visits_nodes_once() {
+        let map = map("111\n1#1\n111");
+
+        let (path, cost) = longest_path(&map, (0, 0), &(0, 1)).unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 2), (0, 2), (0, 1)]);
+    }
+}
@@ -0,0 +1,81 @@
+use std::{env, path::PathBuf, process};
+
+use crate::{input, parse::report, Answer, Result};
+
+// A day's puzzle: the input is parsed once and both parts are solved from that shared state
+pub trait Solution {
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    // Both answers straight from the raw puzzle text, for tools that embed a day
+    fn solve(input: &str) -> Result<(Self::Answer1, Self::Answer2)> where Self: Sized {
+        let parsed = parse::<Self>(input)?;
+        Ok((Self::part1(&parsed)?, Self::part2(&parsed)?))
+    }
+}
+
+// Days whose parsed input prints back as canonical puzzle text, which parses to the same input again
+pub trait Print: Solution {
+    fn print(input: &Self::Input) -> String;
+}
+
+// Parses through the shared normalisation so every day sees the same LF separated input
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input> {
+    S::parse(&input::normalize(input))
+}
+
+// Entry point of every day binary: solve the file given as the first argument, `-` for stdin,
+// or input.txt, and print both answers
+pub fn run<S: Solution>() -> Result<()> {
+    let path = env::args().nth(1).map_or_else(|| PathBuf::from(input::INPUT_FILE), PathBuf::from);
+    let input = input::read_input_from(&path)?;
+    let parsed = match parse::<S>(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", report(&e, &path, &input));
+            process::exit(1);
+        }
+    };
+
+    println!("Part 1 answer: {}", S::part1(&parsed)?.into());
+    println!("Part 2 answer: {}", S::part2(&parsed)?.into());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use crate::Result;
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input = Vec<i64>;
+        type Answer1 = i64;
+        type Answer2 = i64;
+
+        fn parse(input: &str) -> Result<Vec<i64>> {
+            Ok(input.lines().map(str::parse).collect::<std::result::Result<_, _>>()?)
+        }
+
+        fn part1(numbers: &Vec<i64>) -> Result<i64> {
+            Ok(numbers.iter().sum())
+        }
+
+        fn part2(numbers: &Vec<i64>) -> Result<i64> {
+            Ok(numbers.iter().copied().max().unwrap_or_default())
+        }
+    }
+
+    #[test]
+    fn solves_raw_input() {
+        assert_eq!(Numbers::solve("1\r\n5\r\n3\r\n").unwrap(), (9, 5));
+        assert!(Numbers::solve("1\nx").is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use std::{fmt::Debug, ops::Range, path::Path};
+
+use crate::{parse::report, solution, Answer, Configurable, Print, Solution};
+
+// Helpers for the example tests of every day; failures panic with the same report the runner prints
+
+pub fn parse<S: Solution>(input: &str) -> S::Input {
+    solution::parse::<S>(input).unwrap_or_else(|e| panic!("{}", report(&e, Path::new("example"), input)))
+}
+
+pub fn part1<S: Solution>(input: &str) -> String {
+    let answer: Answer = S::part1(&parse::<S>(input)).unwrap_or_else(|e| panic!("part 1 failed: {e}")).into();
+    answer.to_string()
+}
+
+pub fn part2<S: Solution>(input: &str) -> String {
+    let answer: Answer = S::part2(&parse::<S>(input)).unwrap_or_else(|e| panic!("part 2 failed: {e}")).into();
+    answer.to_string()
+}
+
+// The parts with parameters other than the puzzle's
+pub fn part1_with<S: Configurable>(input: &str, params: &S::Params) -> String {
+    let answer: Answer = S::part1_with(&parse::<S>(input), params).unwrap_or_else(|e| panic!("part 1 failed: {e}")).into();
+    answer.to_string()
+}
+
+pub fn part2_with<S: Configurable>(input: &str, params: &S::Params) -> String {
+    let answer: Answer = S::part2_with(&parse::<S>(input), params).unwrap_or_else(|e| panic!("part 2 failed: {e}")).into();
+    answer.to_string()
+}
+
+// Runs the clever solver and a slow, obviously correct reference on the generated input of every seed
+// and fails with every seed they disagree on, along with the first input that shows it
+pub fn differential<T: PartialEq + Debug>(
+    seeds: Range<u64>,
+    generate: impl Fn(u64) -> String,
+    clever: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+) {
+    let mut disagreements = Vec::new();
+    let mut first = None;
+    for seed in seeds {
+        let input = generate(seed);
+        let (answer, expected) = (clever(&input), reference(&input));
+        if answer != expected {
+            disagreements.push(format!("seed {seed}: got {answer:?}, the reference says {expected:?}"));
+            first.get_or_insert(input);
+        }
+    }
+
+    if let Some(input) = first {
+        panic!("{} disagreements\n{}\nfirst input:\n{input}", disagreements.len(), disagreements.join("\n"));
+    }
+}
+
+// Prints the parsed input of every seed and parses that again, failing with every seed that doesn't come
+// back the same or doesn't print the same twice, along with the first printed text that shows it
+pub fn round_trip<S: Print>(seeds: Range<u64>, generate: impl Fn(u64) -> String) where S::Input: PartialEq {
+    let mut failures = Vec::new();
+    let mut first = None;
+    for seed in seeds {
+        let parsed = parse::<S>(&generate(seed));
+        let printed = S::print(&parsed);
+        let again = parse::<S>(&printed);
+        if again != parsed {
+            failures.push(format!("seed {seed}: parses differently once printed"));
+        } else if S::print(&again) != printed {
+            failures.push(format!("seed {seed}: prints differently once parsed again"));
+        } else {
+            continue;
+        }
+        first.get_or_insert(printed);
+    }
+
+    if let Some(printed) = first {
+        panic!("{} failures\n{}\nfirst printed input:\n{printed}", failures.len(), failures.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{differential, round_trip};
+    use crate::{Print, Result, Solution};
+
+    // Numbers one per line, `print` dropping every number after a 7
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(input: &str) -> Result<Vec<u64>> {
+            Ok(input.lines().map(str::parse).collect::<std::result::Result<_, _>>()?)
+        }
+
+        fn part1(numbers: &Vec<u64>) -> Result<u64> {
+            Ok(numbers.iter().sum())
+        }
+
+        fn part2(numbers: &Vec<u64>) -> Result<u64> {
+            Ok(numbers.len() as u64)
+        }
+    }
+
+    impl Print for Numbers {
+        fn print(numbers: &Vec<u64>) -> String {
+            let kept = numbers.iter().position(|&n| n == 7).map_or(numbers.len(), |i| i + 1);
+            numbers[..kept].iter().map(u64::to_string).collect::<Vec<_>>().join("\n")
+        }
+    }
+
+    #[test]
+    fn agreeing_solvers_pass() {
+        differential(0..10, |seed| seed.to_string(), |input| input.len(), |input| input.chars().count());
+    }
+
+    #[test]
+    #[should_panic(expected = "1 disagreements\nseed 7: got 8, the reference says 7")]
+    fn reports_disagreeing_seeds() {
+        let off_on_seven = |input: &str| match input.parse::<u64>().unwrap() {
+            7 => 8,
+            n => n,
+        };
+        differential(0..10, |seed| seed.to_string(), off_on_seven, |input| input.parse::<u64>().unwrap());
+    }
+
+    #[test]
+    fn printed_inputs_parse_back() {
+        round_trip::<Numbers>(0..5, |seed| format!("{seed}\n{}", seed + 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "1 failures\nseed 7: parses differently once printed\nfirst printed input:\n7")]
+    fn reports_lossy_printing() {
+        round_trip::<Numbers>(0..10, |seed| format!("{seed}\n{}", seed + 1));
+    }
+}
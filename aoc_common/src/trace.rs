@@ -0,0 +1,135 @@
+use std::{cell::RefCell, fs::File, io::{self, BufWriter, Write}, path::Path, sync::{atomic::{AtomicU64, AtomicU8, Ordering}, Mutex}};
+
+use serde_json::{Map, Value};
+
+pub use serde_json::json;
+
+// What the solvers did step by step, one JSON object per line, for when an answer is wrong. Events are
+// only built when their level is traced, so with tracing off an event costs one relaxed atomic load
+
+// Levels, each tracing the ones before it too
+pub const MILESTONE: u8 = 1;
+pub const STEP: u8 = 2;
+pub const DETAIL: u8 = 3;
+
+// 0 while tracing is off
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+static SEQ: AtomicU64 = AtomicU64::new(0);
+static SINK: Mutex<Option<BufWriter<File>>> = Mutex::new(None);
+
+// The part this thread is solving, stamped onto its events
+struct Context {
+    day: u32,
+    part: u32,
+    input: String,
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+// Traces events up to `level` into `path` until `finish`
+pub fn start(path: &Path, level: u8) -> io::Result<()> {
+    let file = File::create(path)?;
+    *SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(BufWriter::new(file));
+    LEVEL.store(level, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn finish() -> io::Result<()> {
+    LEVEL.store(0, Ordering::Relaxed);
+    match SINK.lock().unwrap_or_else(|e| e.into_inner()).take() {
+        Some(mut sink) => sink.flush(),
+        None => Ok(()),
+    }
+}
+
+#[inline]
+pub fn enabled(level: u8) -> bool {
+    level <= LEVEL.load(Ordering::Relaxed)
+}
+
+// Events emitted by `f` belong to part `part` of day `day` on `input`
+pub fn in_part<T>(day: u32, part: u32, input: &Path, f: impl FnOnce() -> T) -> T {
+    if !enabled(MILESTONE) {
+        return f();
+    }
+
+    let previous = CONTEXT.replace(Some(Context { day, part, input: input.display().to_string() }));
+    let result = f();
+    CONTEXT.set(previous);
+    result
+}
+
+// Use `trace!` instead, which skips building the fields while `level` is off
+pub fn emit(level: u8, event: &str, fields: Value) {
+    let mut record = Map::new();
+    record.insert("seq".to_string(), SEQ.fetch_add(1, Ordering::Relaxed).into());
+    CONTEXT.with_borrow(|context| {
+        if let Some(Context { day, part, input }) = context {
+            record.insert("day".to_string(), (*day).into());
+            record.insert("part".to_string(), (*part).into());
+            record.insert("input".to_string(), input.as_str().into());
+        }
+    });
+    record.insert("level".to_string(), level.into());
+    record.insert("event".to_string(), event.into());
+    match fields {
+        Value::Object(fields) => record.extend(fields),
+        Value::Null => (),
+        value => _ = record.insert("value".to_string(), value),
+    }
+
+    // A trace that can't be written is no reason to fail the solver, `finish` reports the sink's errors
+    if let Some(sink) = SINK.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+        _ = writeln!(sink, "{}", Value::Object(record));
+    }
+}
+
+// `trace!(trace::STEP, "hop", "from": name, "to": to)` records a `hop` event with those fields
+#[macro_export]
+macro_rules! trace {
+    ($level:expr, $event:expr $(, $($fields:tt)+)?) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit($level, $event, $crate::trace::json!({ $($($fields)+)? }));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{finish, in_part, start, DETAIL, MILESTONE, STEP};
+
+    #[test]
+    fn writes_json_lines_up_to_the_level() {
+        let path = env::temp_dir().join(format!("aoc_trace_{}.jsonl", process::id()));
+
+        trace!(MILESTONE, "before");
+        start(&path, STEP).unwrap();
+        in_part(19, 1, "input.txt".as_ref(), || {
+            trace!(MILESTONE, "accept", "rating": 7540);
+            trace!(STEP, "hop", "from": "in", "to": "qqz");
+            trace!(DETAIL, "ignored");
+        });
+        finish().unwrap();
+        trace!(MILESTONE, "after");
+
+        let lines = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        // Tests running alongside may trace too, without a part of their own
+        let events = lines.lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter(|event| event["day"] == 19)
+            .collect::<Vec<_>>();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["event"], "accept");
+        assert_eq!(events[0]["rating"], 7540);
+        assert_eq!(events[1]["day"], 19);
+        assert_eq!(events[1]["part"], 1);
+        assert_eq!(events[1]["input"], "input.txt");
+        assert_eq!(events[1]["to"], "qqz");
+    }
+}